@@ -0,0 +1,260 @@
+use crate::error::AocError;
+use crate::input::{Checksum, InputSource, InputStore};
+use crate::puzzle::Part;
+use crate::runner::Registry;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+/// Submitted answers keyed by year, day and part, stored as
+///
+/// ```toml
+/// [2022.7]
+/// part1 = "1297683"
+/// part2 = "5756764"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u32, u32, u32), String>,
+}
+
+impl Answers {
+    /// Reads the answers file, treating a missing file as empty.
+    pub fn load(path: &Path) -> Result<Answers, AocError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Answers::parse(&contents).map_err(|reason| AocError::Format {
+                path: path.to_owned(),
+                reason,
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(AocError::io(path, err)),
+        }
+    }
+
+    fn parse(contents: &str) -> Result<Answers, String> {
+        let table: toml::Table = contents.parse().map_err(|err| format!("{err}"))?;
+        let mut answers = Answers::default();
+
+        for (year, days) in &table {
+            let year_number = year.parse().map_err(|_| format!("invalid year `{year}`"))?;
+            let days = days
+                .as_table()
+                .ok_or_else(|| format!("expected days of {year}"))?;
+
+            for (day, parts) in days {
+                let day_number = day.parse().map_err(|_| format!("invalid day `{day}`"))?;
+                let parts = parts
+                    .as_table()
+                    .ok_or_else(|| format!("expected parts of {year}.{day}"))?;
+
+                for (part, answer) in parts {
+                    let part_number = part
+                        .strip_prefix("part")
+                        .and_then(|part| part.parse().ok())
+                        .ok_or_else(|| format!("invalid part `{part}` in {year}.{day}"))?;
+                    let answer = match answer {
+                        toml::Value::String(answer) => answer.clone(),
+                        toml::Value::Integer(answer) => answer.to_string(),
+                        _ => return Err(format!("invalid answer for {year}.{day}.{part}")),
+                    };
+
+                    answers.insert(year_number, day_number, part_number, answer);
+                }
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, year: u32, day: u32, part: u32, answer: String) {
+        self.answers.insert((year, day, part), answer);
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let mut contents = String::new();
+        let mut current_day = None;
+
+        for (&(year, day, part), answer) in &self.answers {
+            if current_day != Some((year, day)) {
+                if current_day.is_some() {
+                    contents.push('\n');
+                }
+                writeln!(contents, "[{year}.{day}]").unwrap();
+                current_day = Some((year, day));
+            }
+
+            let answer = toml::Value::String(answer.clone());
+            writeln!(contents, "part{part} = {answer}").unwrap();
+        }
+
+        fs::write(path, contents).map_err(|err| AocError::io(path, err))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing(String),
+    Error(String),
+}
+
+#[derive(Debug)]
+pub struct Verification {
+    pub day: u32,
+    pub part: u32,
+    pub status: Status,
+    pub input_changed: bool,
+}
+
+impl Verification {
+    pub fn is_regression(&self) -> bool {
+        matches!(self.status, Status::Fail { .. } | Status::Error(_))
+    }
+}
+
+/// Runs every registered solver of `year` against its stored input and compares the results with
+/// the recorded answers.
+pub fn verify(
+    registry: &Registry,
+    year: u32,
+    store: &InputStore,
+    answers: &Answers,
+) -> Vec<Verification> {
+    let mut results = Vec::new();
+
+    for (_, day, solver) in registry.iter().filter(|(y, _, _)| *y == year) {
+        let input = store.load(year, day, &InputSource::Store);
+        let input_changed = matches!(
+            &input,
+            Ok(input) if matches!(input.checksum, Checksum::Changed { .. })
+        );
+        let parsed = input
+            .map_err(|err| Status::Missing(err.to_string()))
+            .and_then(|input| {
+                solver
+                    .parse(&input.text)
+                    .map_err(|err| Status::Error(err.to_string()))
+            });
+
+        for part in Part::ALL {
+            let status = match (answers.get(year, day, part.number()), &parsed) {
+                (None, _) => Status::Missing("no recorded answer".to_owned()),
+                (Some(_), Err(status)) => status.clone(),
+                (Some(expected), Ok(parsed)) => {
+                    let actual = solver.solve_parsed(part, parsed.as_ref()).to_string();
+
+                    if actual == expected {
+                        Status::Pass
+                    } else {
+                        Status::Fail {
+                            expected: expected.to_owned(),
+                            actual,
+                        }
+                    }
+                }
+            };
+
+            results.push(Verification {
+                day,
+                part: part.number(),
+                status,
+                input_changed,
+            });
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static ANSWERS: &str = r#"[2022.6]
+part1 = "5"
+part2 = "23"
+
+[2022.10]
+part1 = "13140"
+part2 = """
+##..
+###."""
+"#;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(answers.get(2022, 6, 1), Some("5"));
+        assert_eq!(answers.get(2022, 10, 2), Some("##..\n###."));
+        assert_eq!(answers.get(2022, 7, 1), None);
+    }
+
+    #[test]
+    fn test_parse_integer_answer() {
+        let answers = Answers::parse("[2022.1]\npart1 = 24000\n").unwrap();
+
+        assert_eq!(answers.get(2022, 1, 1), Some("24000"));
+    }
+
+    #[test]
+    fn test_parse_invalid_part() {
+        assert_eq!(
+            Answers::parse("[2022.1]\nthird = 1\n"),
+            Err("invalid part `third` in 2022.1".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_save_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        answers.save(&path).unwrap();
+
+        assert_eq!(Answers::load(&path).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_verify() {
+        let root = std::env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+        fs::create_dir_all(root.join("2022")).unwrap();
+        let store = InputStore::new(&root);
+        fs::write(store.path(2022, 6), "bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap();
+
+        let mut answers = Answers::default();
+        answers.insert(2022, 6, 1, "5".to_owned());
+        answers.insert(2022, 6, 2, "24".to_owned());
+        answers.insert(2022, 7, 1, "95437".to_owned());
+
+        let results = verify(&crate::runner::registry(), 2022, &store, &answers);
+        let status = |day, part| {
+            &results
+                .iter()
+                .find(|result| result.day == day && result.part == part)
+                .unwrap()
+                .status
+        };
+
+        assert_eq!(status(6, 1), &Status::Pass);
+        assert_eq!(
+            status(6, 2),
+            &Status::Fail {
+                expected: "24".to_owned(),
+                actual: "23".to_owned()
+            }
+        );
+        assert!(matches!(status(7, 1), Status::Missing(_)));
+        assert!(matches!(status(8, 1), Status::Missing(_)));
+        assert_eq!(results.iter().filter(|r| r.is_regression()).count(), 1);
+    }
+}
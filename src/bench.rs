@@ -0,0 +1,272 @@
+use crate::error::AocError;
+use crate::puzzle::{Part, Solver};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_WARMUP: usize = 3;
+pub const DEFAULT_SAMPLES: usize = 25;
+/// Relative slowdown of the median which counts as a regression, 0.1 being 10%.
+pub const DEFAULT_THRESHOLD: f64 = 0.1;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    /// Untimed runs before sampling starts.
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: DEFAULT_WARMUP,
+            samples: DEFAULT_SAMPLES,
+        }
+    }
+}
+
+/// Timings of one phase in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    /// Panics if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+
+        let middle = nanos.len() / 2;
+        let median_ns = if nanos.len().is_multiple_of(2) {
+            (nanos[middle - 1] + nanos[middle]) / 2
+        } else {
+            nanos[middle]
+        };
+        // nearest-rank percentile
+        let p95_rank = (nanos.len() * 95).div_ceil(100);
+
+        Stats {
+            median_ns,
+            p95_ns: nanos[p95_rank - 1],
+            min_ns: nanos[0],
+            max_ns: nanos[nanos.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayReport {
+    pub year: u32,
+    pub day: u32,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayReport {
+    pub fn phases(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub warmup: usize,
+    pub samples: usize,
+    pub days: Vec<DayReport>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Report, AocError> {
+        let contents = fs::read_to_string(path).map_err(|err| AocError::io(path, err))?;
+
+        serde_json::from_str(&contents).map_err(|err| AocError::Format {
+            path: path.to_owned(),
+            reason: err.to_string(),
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let contents = serde_json::to_string_pretty(self).expect("report should serialize");
+
+        fs::write(path, contents + "\n").map_err(|err| AocError::io(path, err))
+    }
+}
+
+fn measure<T>(config: &BenchConfig, mut run: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(run());
+    }
+
+    let samples: Vec<Duration> = (0..config.samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(run());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(&samples)
+}
+
+/// Times the generator and both parts of one day separately. The parts are timed on an input
+/// which was parsed once up front.
+pub fn bench_day(
+    solver: &dyn Solver,
+    year: u32,
+    day: u32,
+    input: &str,
+    config: &BenchConfig,
+) -> Result<DayReport, AocError> {
+    let parsed = solver.parse(input)?;
+    let parse = measure(config, || solver.parse(black_box(input)));
+
+    let part1 = measure(config, || solver.solve_parsed(Part::One, parsed.as_ref()));
+    let part2 = measure(config, || solver.solve_parsed(Part::Two, parsed.as_ref()));
+
+    Ok(DayReport {
+        year,
+        day,
+        parse,
+        part1,
+        part2,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub year: u32,
+    pub day: u32,
+    pub phase: &'static str,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+}
+
+impl Regression {
+    pub fn slowdown(&self) -> f64 {
+        self.current_ns as f64 / self.baseline_ns.max(1) as f64 - 1.0
+    }
+}
+
+/// Lists every phase whose median got slower than the baseline by more than `threshold`.
+/// Days missing from the baseline are skipped.
+pub fn compare(current: &Report, baseline: &Report, threshold: f64) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+
+    for day in &current.days {
+        let Some(base) = baseline
+            .days
+            .iter()
+            .find(|base| base.year == day.year && base.day == day.day)
+        else {
+            continue;
+        };
+
+        for ((phase, stats), (_, base_stats)) in day.phases().into_iter().zip(base.phases()) {
+            if stats.median_ns as f64 > base_stats.median_ns as f64 * (1.0 + threshold) {
+                regressions.push(Regression {
+                    year: day.year,
+                    day: day.day,
+                    phase,
+                    baseline_ns: base_stats.median_ns,
+                    current_ns: stats.median_ns,
+                });
+            }
+        }
+    }
+
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::registry;
+
+    fn stats(median_ns: u64) -> Stats {
+        Stats {
+            median_ns,
+            p95_ns: median_ns,
+            min_ns: median_ns,
+            max_ns: median_ns,
+        }
+    }
+
+    fn report(part2_ns: u64) -> Report {
+        Report {
+            warmup: 1,
+            samples: 1,
+            days: vec![DayReport {
+                year: 2022,
+                day: 11,
+                parse: stats(1000),
+                part1: stats(2000),
+                part2: stats(part2_ns),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = (1..=20).rev().map(Duration::from_nanos).collect();
+
+        assert_eq!(
+            Stats::from_samples(&samples),
+            Stats {
+                median_ns: 10,
+                p95_ns: 19,
+                min_ns: 1,
+                max_ns: 20,
+            }
+        );
+        assert_eq!(Stats::from_samples(&samples[..3]).median_ns, 19);
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = report(10_000);
+
+        assert!(compare(&report(10_900), &baseline, 0.1).is_empty());
+
+        let regressions = compare(&report(12_000), &baseline, 0.1);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].phase, "part2");
+        assert!((regressions[0].slowdown() - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_report_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.json", std::process::id()));
+        let report = report(10_000);
+
+        report.save(&path).unwrap();
+
+        assert_eq!(Report::load(&path).unwrap(), report);
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let registry = registry();
+        let solver = registry.get(2022, 6).unwrap();
+        let config = BenchConfig {
+            warmup: 1,
+            samples: 3,
+        };
+
+        let report = bench_day(solver, 2022, 6, "bvwbjplbgvbhsrlpgdmjqwftvncz", &config).unwrap();
+
+        assert_eq!(report.day, 6);
+        assert!(report.part1.min_ns <= report.part1.p95_ns);
+        assert!(bench_day(registry.get(2022, 12).unwrap(), 2022, 12, "S?E", &config).is_err());
+    }
+}
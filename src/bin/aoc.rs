@@ -0,0 +1,458 @@
+use aoc_rust::answers::{self, Answers, Status};
+use aoc_rust::bench::{self, BenchConfig, Report};
+use aoc_rust::input::{self, Checksum, InputSource, InputStore};
+use aoc_rust::puzzle::Part;
+use aoc_rust::runner::{self, Registry};
+use aoc_rust::y2022;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const USAGE: &str = "usage:
+    aoc run --day N [--year Y] [--part P] [--input FILE|-] [--record] [--inputs DIR] [--answers FILE]
+        run one day with its stored input, FILE or stdin (`-`);
+        --record stores the answers and the checksum of the stored input
+    aoc list
+        list all available solutions
+    aoc all [--year Y] [--inputs DIR]
+        run every day with its stored input
+    aoc verify [--year Y] [--inputs DIR] [--answers FILE]
+        check every solution against its recorded answer, fails on any regression
+    aoc bench [--year Y] [--day N] [--warmup N] [--samples N] [--output FILE] [--baseline FILE]
+              [--threshold PCT] [--inputs DIR]
+        time parsing and both parts of every day (or day N) on its stored input;
+        --output writes a JSON report, --baseline fails if a median got slower than
+        the one in the baseline report by more than PCT percent (default 10)
+
+every command but list works on a single year (default: 2022)
+inputs are stored as DIR/<year>/day<NN>.txt (default DIR: inputs)
+answers are stored in FILE (default: answers.toml)";
+
+const DEFAULT_YEAR: u32 = y2022::YEAR;
+
+enum Command {
+    Run {
+        year: u32,
+        day: u32,
+        part: Option<u32>,
+        source: InputSource,
+        record: bool,
+        inputs: PathBuf,
+        answers: PathBuf,
+    },
+    List,
+    All {
+        year: u32,
+        inputs: PathBuf,
+    },
+    Verify {
+        year: u32,
+        inputs: PathBuf,
+        answers: PathBuf,
+    },
+    Bench {
+        year: u32,
+        day: Option<u32>,
+        config: BenchConfig,
+        output: Option<PathBuf>,
+        baseline: Option<PathBuf>,
+        threshold: f64,
+        inputs: PathBuf,
+    },
+}
+
+fn parse_number(flag: &str, value: Option<String>) -> Result<u32, String> {
+    let value = value.ok_or_else(|| format!("missing value for {flag}"))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for {flag}: `{value}`"))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let command = args.next().ok_or("missing command")?;
+
+    match command.as_str() {
+        "run" => {
+            let mut year = DEFAULT_YEAR;
+            let mut day = None;
+            let mut part = None;
+            let mut source = InputSource::Store;
+            let mut record = false;
+            let mut inputs = PathBuf::from(input::DEFAULT_ROOT);
+            let mut answers = PathBuf::from(answers::DEFAULT_PATH);
+
+            while let Some(flag) = args.next() {
+                match flag.as_str() {
+                    "--year" => year = parse_number(&flag, args.next())?,
+                    "--day" => day = Some(parse_number(&flag, args.next())?),
+                    "--part" => part = Some(parse_number(&flag, args.next())?),
+                    "--input" => {
+                        let arg = args.next().ok_or("missing value for --input")?;
+                        source = InputSource::from_arg(&arg);
+                    }
+                    "--record" => record = true,
+                    "--inputs" => inputs = args.next().ok_or("missing value for --inputs")?.into(),
+                    "--answers" => {
+                        answers = args.next().ok_or("missing value for --answers")?.into()
+                    }
+                    _ => return Err(format!("unknown argument `{flag}`")),
+                }
+            }
+
+            if record && source != InputSource::Store {
+                return Err("--record only works with stored inputs".to_owned());
+            }
+
+            Ok(Command::Run {
+                year,
+                day: day.ok_or("missing --day")?,
+                part,
+                source,
+                record,
+                inputs,
+                answers,
+            })
+        }
+        "list" => Ok(Command::List),
+        "all" => {
+            let mut year = DEFAULT_YEAR;
+            let mut inputs = PathBuf::from(input::DEFAULT_ROOT);
+
+            while let Some(flag) = args.next() {
+                match flag.as_str() {
+                    "--year" => year = parse_number(&flag, args.next())?,
+                    "--inputs" => inputs = args.next().ok_or("missing value for --inputs")?.into(),
+                    _ => return Err(format!("unknown argument `{flag}`")),
+                }
+            }
+
+            Ok(Command::All { year, inputs })
+        }
+        "verify" => {
+            let mut year = DEFAULT_YEAR;
+            let mut inputs = PathBuf::from(input::DEFAULT_ROOT);
+            let mut answers = PathBuf::from(answers::DEFAULT_PATH);
+
+            while let Some(flag) = args.next() {
+                match flag.as_str() {
+                    "--year" => year = parse_number(&flag, args.next())?,
+                    "--inputs" => inputs = args.next().ok_or("missing value for --inputs")?.into(),
+                    "--answers" => {
+                        answers = args.next().ok_or("missing value for --answers")?.into()
+                    }
+                    _ => return Err(format!("unknown argument `{flag}`")),
+                }
+            }
+
+            Ok(Command::Verify {
+                year,
+                inputs,
+                answers,
+            })
+        }
+        "bench" => {
+            let mut year = DEFAULT_YEAR;
+            let mut day = None;
+            let mut config = BenchConfig::default();
+            let mut output = None;
+            let mut baseline = None;
+            let mut threshold = bench::DEFAULT_THRESHOLD;
+            let mut inputs = PathBuf::from(input::DEFAULT_ROOT);
+
+            while let Some(flag) = args.next() {
+                match flag.as_str() {
+                    "--year" => year = parse_number(&flag, args.next())?,
+                    "--day" => day = Some(parse_number(&flag, args.next())?),
+                    "--warmup" => config.warmup = parse_number(&flag, args.next())? as usize,
+                    "--samples" => {
+                        config.samples = parse_number(&flag, args.next())?.max(1) as usize
+                    }
+                    "--output" => {
+                        output = Some(args.next().ok_or("missing value for --output")?.into())
+                    }
+                    "--baseline" => {
+                        baseline = Some(args.next().ok_or("missing value for --baseline")?.into())
+                    }
+                    "--threshold" => threshold = parse_number(&flag, args.next())? as f64 / 100.0,
+                    "--inputs" => inputs = args.next().ok_or("missing value for --inputs")?.into(),
+                    _ => return Err(format!("unknown argument `{flag}`")),
+                }
+            }
+
+            Ok(Command::Bench {
+                year,
+                day,
+                config,
+                output,
+                baseline,
+                threshold,
+                inputs,
+            })
+        }
+        _ => Err(format!("unknown command `{command}`")),
+    }
+}
+
+/// Loads the puzzle input and warns if it changed since its checksum was recorded.
+fn read_input(
+    store: &InputStore,
+    year: u32,
+    day: u32,
+    source: &InputSource,
+) -> Result<String, String> {
+    let input = store
+        .load(year, day, source)
+        .map_err(|err| err.to_string())?;
+
+    if let Checksum::Changed { recorded, actual } = &input.checksum {
+        eprintln!(
+            "warning: {} changed since its answers were recorded (recorded {recorded}, now {actual})",
+            store.path(year, day).display()
+        );
+    }
+
+    Ok(input.text)
+}
+
+/// Runs the given part or all parts of a day and returns the answers by part.
+fn run(
+    registry: &Registry,
+    year: u32,
+    day: u32,
+    part: Option<u32>,
+    input: &str,
+) -> Result<Vec<(u32, String)>, String> {
+    let solver = registry
+        .get(year, day)
+        .ok_or_else(|| format!("no solutions for {year} day {day}"))?;
+    let parts = match part {
+        Some(number) => vec![Part::from_number(number)
+            .ok_or_else(|| format!("no solution for day {day} part {number}"))?],
+        None => Part::ALL.to_vec(),
+    };
+
+    let parsed = solver.parse(input).map_err(|err| err.to_string())?;
+
+    let mut answers = Vec::with_capacity(parts.len());
+    for part in parts {
+        let answer = solver.solve_parsed(part, parsed.as_ref()).to_string();
+        println!("day {day} part {part}: {answer}");
+        answers.push((part.number(), answer));
+    }
+
+    Ok(answers)
+}
+
+fn verify(
+    registry: &Registry,
+    year: u32,
+    store: &InputStore,
+    answers_path: &Path,
+) -> Result<(), String> {
+    let answers = Answers::load(answers_path).map_err(|err| err.to_string())?;
+    let results = answers::verify(registry, year, store, &answers);
+
+    println!("{:>4} {:>4}  {:<7}  details", "day", "part", "status");
+    for result in &results {
+        let (status, details) = match &result.status {
+            Status::Pass => ("pass", String::new()),
+            Status::Fail { expected, actual } => {
+                ("FAIL", format!("expected {expected:?}, got {actual:?}"))
+            }
+            Status::Missing(reason) => ("missing", reason.clone()),
+            Status::Error(reason) => ("ERROR", reason.clone()),
+        };
+        let details = if result.input_changed {
+            format!("{details} (input changed since recording)")
+        } else {
+            details
+        };
+
+        let row = format!(
+            "{:>4} {:>4}  {status:<7}  {details}",
+            result.day, result.part
+        );
+        println!("{}", row.trim_end());
+    }
+
+    let regressions = results.iter().filter(|r| r.is_regression()).count();
+    if regressions > 0 {
+        return Err(format!("{regressions} regression(s)"));
+    }
+
+    Ok(())
+}
+
+fn format_nanos(nanos: u64) -> String {
+    match nanos {
+        0..=9_999 => format!("{nanos}ns"),
+        10_000..=9_999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        _ => format!("{:.1}ms", nanos as f64 / 1e6),
+    }
+}
+
+fn bench(
+    registry: &Registry,
+    year: u32,
+    day: Option<u32>,
+    config: &BenchConfig,
+    store: &InputStore,
+    output: Option<&Path>,
+    baseline: Option<(&Path, f64)>,
+) -> Result<(), String> {
+    let days: Vec<_> = registry
+        .iter()
+        .filter(|(y, d, _)| *y == year && day.is_none_or(|day| day == *d))
+        .collect();
+    if days.is_empty() {
+        return Err(match day {
+            Some(day) => format!("no solutions for {year} day {day}"),
+            None => format!("no solutions for {year}"),
+        });
+    }
+
+    let mut report = Report {
+        warmup: config.warmup,
+        samples: config.samples,
+        days: Vec::new(),
+    };
+
+    println!(
+        "{:>4}  {:<5}  {:>10}  {:>10}  {:>10}",
+        "day", "phase", "median", "p95", "min"
+    );
+    for (year, day, solver) in days {
+        let input = read_input(store, year, day, &InputSource::Store)?;
+        let result =
+            bench::bench_day(solver, year, day, &input, config).map_err(|err| err.to_string())?;
+
+        for (phase, stats) in result.phases() {
+            println!(
+                "{day:>4}  {phase:<5}  {:>10}  {:>10}  {:>10}",
+                format_nanos(stats.median_ns),
+                format_nanos(stats.p95_ns),
+                format_nanos(stats.min_ns)
+            );
+        }
+        report.days.push(result);
+    }
+
+    if let Some(output) = output {
+        report.save(output).map_err(|err| err.to_string())?;
+    }
+
+    if let Some((baseline, threshold)) = baseline {
+        let baseline = Report::load(baseline).map_err(|err| err.to_string())?;
+        let regressions = bench::compare(&report, &baseline, threshold);
+
+        for regression in &regressions {
+            eprintln!(
+                "regression: day {} {} {} -> {} (+{:.0}%)",
+                regression.day,
+                regression.phase,
+                format_nanos(regression.baseline_ns),
+                format_nanos(regression.current_ns),
+                regression.slowdown() * 100.0
+            );
+        }
+        if !regressions.is_empty() {
+            return Err(format!("{} regression(s)", regressions.len()));
+        }
+    }
+
+    Ok(())
+}
+
+fn execute(command: Command) -> Result<(), String> {
+    let registry = runner::registry();
+
+    match command {
+        Command::Run {
+            year,
+            day,
+            part,
+            source,
+            record,
+            inputs,
+            answers,
+        } => {
+            let store = InputStore::new(inputs);
+            let input = read_input(&store, year, day, &source)?;
+            let results = run(&registry, year, day, part, &input)?;
+
+            if record {
+                let mut recorded = Answers::load(&answers).map_err(|err| err.to_string())?;
+                for (part, answer) in results {
+                    recorded.insert(year, day, part, answer);
+                }
+                recorded.save(&answers).map_err(|err| err.to_string())?;
+
+                let checksum = store.record(year, day).map_err(|err| err.to_string())?;
+                eprintln!(
+                    "recorded {checksum} for {}",
+                    store.path(year, day).display()
+                );
+            }
+            Ok(())
+        }
+        Command::List => {
+            for (year, day, _) in registry.iter() {
+                for part in Part::ALL {
+                    println!("{year} day {day} part {part}");
+                }
+            }
+            Ok(())
+        }
+        Command::All { year, inputs } => {
+            if !registry.years().contains(&year) {
+                return Err(format!("no solutions for {year}"));
+            }
+
+            let store = InputStore::new(inputs);
+            for (_, day, _) in registry.iter().filter(|(y, _, _)| *y == year) {
+                let input = read_input(&store, year, day, &InputSource::Store)?;
+                run(&registry, year, day, None, &input)?;
+            }
+            Ok(())
+        }
+        Command::Verify {
+            year,
+            inputs,
+            answers,
+        } => verify(&registry, year, &InputStore::new(inputs), &answers),
+        Command::Bench {
+            year,
+            day,
+            config,
+            output,
+            baseline,
+            threshold,
+            inputs,
+        } => bench(
+            &registry,
+            year,
+            day,
+            &config,
+            &InputStore::new(inputs),
+            output.as_deref(),
+            baseline.as_deref().map(|baseline| (baseline, threshold)),
+        ),
+    }
+}
+
+fn main() -> ExitCode {
+    let command = match parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("error: {err}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match execute(command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
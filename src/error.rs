@@ -0,0 +1,180 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    Parse {
+        day: u32,
+        line: usize,
+        column: usize,
+        text: String,
+        reason: String,
+    },
+    UnknownPuzzle {
+        day: u32,
+        part: u32,
+    },
+    Io {
+        path: PathBuf,
+        reason: String,
+    },
+    Format {
+        path: PathBuf,
+        reason: String,
+    },
+    /// Input that parses, but that a day can't compute its result from.
+    Unusable {
+        day: u32,
+        reason: String,
+    },
+}
+
+impl AocError {
+    /// Creates a parse error for `token` on the given 1-based line.
+    ///
+    /// `token` is expected to be a sub-slice of `line` so the column can be derived from it,
+    /// otherwise the error points at the start of the line.
+    pub fn parse(day: u32, line_number: usize, line: &str, token: &str, reason: &str) -> AocError {
+        AocError::Parse {
+            day,
+            line: line_number,
+            column: column_of(line, token),
+            text: token.to_owned(),
+            reason: reason.to_owned(),
+        }
+    }
+
+    /// Creates a parse error for `token` somewhere in the multi-line `input`, deriving the line
+    /// and column from where `token` is located in `input`. Only the part of `token` on its first
+    /// line is reported.
+    pub fn at(day: u32, input: &str, token: &str, reason: &str) -> AocError {
+        let input_start = input.as_ptr() as usize;
+        let token_start = token.as_ptr() as usize;
+        let offset = if token_start >= input_start && token_start <= input_start + input.len() {
+            token_start - input_start
+        } else {
+            0
+        };
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |index| offset + index);
+        let token = match &input[offset..line_end] {
+            // point at the next character rather than at nothing in the middle of a line
+            rest if token.is_empty() => &rest[..rest.chars().next().map_or(0, char::len_utf8)],
+            rest => &token[..token.len().min(rest.len())],
+        };
+
+        AocError::parse(
+            day,
+            input[..offset].matches('\n').count() + 1,
+            &input[line_start..line_end],
+            token,
+            reason,
+        )
+    }
+
+    pub fn io(path: &Path, err: io::Error) -> AocError {
+        AocError::Io {
+            path: path.to_owned(),
+            reason: err.to_string(),
+        }
+    }
+}
+
+fn column_of(line: &str, token: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let token_start = token.as_ptr() as usize;
+
+    if token_start >= line_start && token_start + token.len() <= line_start + line.len() {
+        line[..token_start - line_start].chars().count() + 1
+    } else {
+        1
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Parse {
+                day,
+                line,
+                column,
+                text,
+                reason,
+            } if text.is_empty() => write!(
+                f,
+                "day {day}, line {line}, column {column}: {reason}, found end of line"
+            ),
+            AocError::Parse {
+                day,
+                line,
+                column,
+                text,
+                reason,
+            } => write!(
+                f,
+                "day {day}, line {line}, column {column}: {reason}, found `{text}`"
+            ),
+            AocError::UnknownPuzzle { day, part } => {
+                write!(f, "no solution for day {day} part {part}")
+            }
+            AocError::Io { path, reason } => write!(f, "{}: {reason}", path.display()),
+            AocError::Format { path, reason } => {
+                write!(f, "{}: invalid format: {reason}", path.display())
+            }
+            AocError::Unusable { day, reason } => write!(f, "day {day}: {reason}"),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_of_token() {
+        let line = "move 1 from x to 3";
+        let error = AocError::parse(5, 2, line, &line[12..13], "expected a number");
+
+        assert_eq!(
+            error.to_string(),
+            "day 5, line 2, column 13: expected a number, found `x`"
+        );
+    }
+
+    #[test]
+    fn test_at_token_in_input() {
+        let input = "move 1 from 2 to 1\nmove 3 from 1 to";
+
+        assert_eq!(
+            AocError::at(5, input, &input[32..], "expected ` to `").to_string(),
+            "day 5, line 2, column 14: expected ` to `, found ` to`"
+        );
+        assert_eq!(
+            AocError::at(5, input, &input[input.len()..], "expected a number").to_string(),
+            "day 5, line 2, column 17: expected a number, found end of line"
+        );
+    }
+
+    #[test]
+    fn test_column_of_foreign_token() {
+        let error = AocError::parse(1, 1, "abc", "xyz", "expected a number");
+
+        assert_eq!(
+            error,
+            AocError::Parse {
+                day: 1,
+                line: 1,
+                column: 1,
+                text: "xyz".to_owned(),
+                reason: "expected a number".to_owned(),
+            }
+        );
+    }
+}
@@ -0,0 +1,329 @@
+use grid::Grid;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on a 2D plane. `x` grows to the right and `y` grows downwards, so `x` is the column
+/// and `y` is the row when a point is used to index a [`Grid`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    /// Converts a `(row, col)` index of a [`Grid`] into a point.
+    pub fn from_grid_index(row: usize, col: usize) -> Point {
+        Point::new(col as i32, row as i32)
+    }
+
+    /// Returns the `(row, col)` index of this point if it lies within `grid`.
+    pub fn to_grid_index<T>(self, grid: &Grid<T>) -> Option<(usize, usize)> {
+        let row = usize::try_from(self.y).ok()?;
+        let col = usize::try_from(self.x).ok()?;
+
+        (row < grid.rows() && col < grid.cols()).then_some((row, col))
+    }
+
+    /// Returns the cell of `grid` at this point, or `None` if the point is out of bounds.
+    pub fn get<T>(self, grid: &Grid<T>) -> Option<&T> {
+        let (row, col) = self.to_grid_index(grid)?;
+        grid.get(row, col)
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev_distance(self, other: Point) -> u32 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Limits both coordinates to -1, 0 or 1.
+    pub fn signum(self) -> Point {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The four orthogonally adjacent points.
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self + direction.offset())
+    }
+
+    /// The eight orthogonally and diagonally adjacent points.
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        (-1..=1)
+            .flat_map(|y| (-1..=1).map(move |x| Point::new(x, y)))
+            .filter(|offset| *offset != Point::ORIGIN)
+            .map(move |offset| self + offset)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i32) -> Point {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// A position in 3D space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Point3 {
+    pub const fn new(x: i32, y: i32, z: i32) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    /// The six points sharing a face with this one.
+    pub fn neighbors6(self) -> impl Iterator<Item = Point3> {
+        [
+            Point3::new(-1, 0, 0),
+            Point3::new(1, 0, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, 0, -1),
+            Point3::new(0, 0, 1),
+        ]
+        .into_iter()
+        .map(move |offset| self + offset)
+    }
+
+    /// The smallest and largest coordinates of `points` on each axis, or `None` if there are none.
+    pub fn bounds(points: impl IntoIterator<Item = Point3>) -> Option<(Point3, Point3)> {
+        points.into_iter().fold(None, |bounds, point| {
+            let (min, max) = bounds.unwrap_or((point, point));
+            Some((
+                Point3::new(min.x.min(point.x), min.y.min(point.y), min.z.min(point.z)),
+                Point3::new(max.x.max(point.x), max.y.max(point.y), max.z.max(point.z)),
+            ))
+        })
+    }
+
+    pub fn dot(self, other: Point3) -> i32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Whether this point lies within the box spanned by `min` and `max`, inclusive.
+    pub fn within(self, min: Point3, max: Point3) -> bool {
+        (min.x..=max.x).contains(&self.x)
+            && (min.y..=max.y).contains(&self.y)
+            && (min.z..=max.z).contains(&self.z)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, rhs: Point3) -> Point3 {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, rhs: Point3) -> Point3 {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Mul<i32> for Point3 {
+    type Output = Point3;
+
+    fn mul(self, rhs: i32) -> Point3 {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Point3;
+
+    fn neg(self) -> Point3 {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// The unit step in this direction. `Up` decreases `y`, matching the row order of a [`Grid`].
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::Right => Point::new(1, 0),
+        }
+    }
+
+    /// The direction after a quarter turn counterclockwise, as seen with `y` growing downwards.
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    /// The direction after a quarter turn clockwise, as seen with `y` growing downwards.
+    pub fn turn_right(self) -> Direction {
+        self.turn_left().opposite()
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let point = Point::new(3, -2);
+
+        assert_eq!(point + Point::new(1, 1), Point::new(4, -1));
+        assert_eq!(point - Point::new(1, 1), Point::new(2, -3));
+        assert_eq!(point * 3, Point::new(9, -6));
+        assert_eq!(-point, Point::new(-3, 2));
+        assert_eq!(point.signum(), Point::new(1, -1));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1, 1);
+        let b = Point::new(4, -1);
+
+        assert_eq!(a.manhattan_distance(b), 5);
+        assert_eq!(a.chebyshev_distance(b), 3);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let neighbors: Vec<Point> = Point::ORIGIN.neighbors4().collect();
+        assert_eq!(
+            neighbors,
+            vec![
+                Point::new(0, -1),
+                Point::new(0, 1),
+                Point::new(-1, 0),
+                Point::new(1, 0)
+            ]
+        );
+
+        assert_eq!(Point::ORIGIN.neighbors8().count(), 8);
+        assert!(Point::ORIGIN
+            .neighbors8()
+            .all(|neighbor| neighbor.chebyshev_distance(Point::ORIGIN) == 1));
+    }
+
+    #[test]
+    fn test_point3() {
+        let point = Point3::new(1, 2, 3);
+
+        assert_eq!(point + Point3::new(1, 1, 1), Point3::new(2, 3, 4));
+        assert_eq!(point - Point3::new(1, 1, 1), Point3::new(0, 1, 2));
+        assert_eq!(point.neighbors6().count(), 6);
+        assert!(point
+            .neighbors6()
+            .all(|neighbor| (neighbor - point) != Point3::default()));
+        assert_eq!(
+            Point3::bounds([point, Point3::new(-1, 5, 0)]),
+            Some((Point3::new(-1, 2, 0), Point3::new(1, 5, 3)))
+        );
+        assert_eq!(point * 2, Point3::new(2, 4, 6));
+        assert_eq!(-point, Point3::new(-1, -2, -3));
+        assert_eq!(point.dot(Point3::new(1, -1, 2)), 5);
+        assert!(point.within(Point3::new(1, 0, 0), Point3::new(1, 2, 3)));
+        assert!(!point.within(Point3::new(2, 0, 0), Point3::new(4, 4, 4)));
+    }
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_left().turn_left(), direction.opposite());
+            assert_eq!(direction.opposite().offset(), -direction.offset());
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    }
+
+    #[test]
+    fn test_grid_index() {
+        let grid = Grid::from_vec(vec![1, 2, 3, 4, 5, 6], 3);
+
+        assert_eq!(Point::new(2, 1).to_grid_index(&grid), Some((1, 2)));
+        assert_eq!(Point::new(2, 1).get(&grid), Some(&6));
+        assert_eq!(Point::new(3, 0).to_grid_index(&grid), None);
+        assert_eq!(Point::new(0, 2).to_grid_index(&grid), None);
+        assert_eq!(Point::new(-1, 0).to_grid_index(&grid), None);
+        assert_eq!(Point::from_grid_index(1, 2), Point::new(2, 1));
+    }
+}
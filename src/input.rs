@@ -0,0 +1,242 @@
+use crate::error::AocError;
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_ROOT: &str = "inputs";
+
+/// Checksums of recorded inputs in `sha256sum` format, so `sha256sum -c` works on it as well.
+const MANIFEST: &str = "manifest.sha256";
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `<root>/<year>/day<NN>.txt` of the [`InputStore`].
+    Store,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Maps `-` to stdin and anything else to a file path.
+    pub fn from_arg(arg: &str) -> InputSource {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(arg.into())
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Checksum {
+    /// The input didn't come from the store, so there is nothing to compare against.
+    NotApplicable,
+    Unrecorded,
+    Matches,
+    Changed {
+        recorded: String,
+        actual: String,
+    },
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub text: String,
+    pub checksum: Checksum,
+}
+
+/// Puzzle inputs laid out as `<root>/<year>/day<NN>.txt`, with a manifest of the checksums of the
+/// inputs whose answers have been recorded.
+pub struct InputStore {
+    root: PathBuf,
+}
+
+impl Default for InputStore {
+    fn default() -> Self {
+        InputStore::new(DEFAULT_ROOT)
+    }
+}
+
+impl InputStore {
+    pub fn new(root: impl Into<PathBuf>) -> InputStore {
+        InputStore { root: root.into() }
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        self.root.join(relative_path(year, day))
+    }
+
+    pub fn load(&self, year: u32, day: u32, source: &InputSource) -> Result<Input, AocError> {
+        match source {
+            InputSource::Store => {
+                let path = self.path(year, day);
+                let text = fs::read_to_string(&path).map_err(|err| AocError::io(&path, err))?;
+                let actual = checksum(&text);
+
+                let checksum = match self.read_manifest()?.remove(&relative_path(year, day)) {
+                    None => Checksum::Unrecorded,
+                    Some(recorded) if recorded == actual => Checksum::Matches,
+                    Some(recorded) => Checksum::Changed { recorded, actual },
+                };
+
+                Ok(Input { text, checksum })
+            }
+            InputSource::Path(path) => Ok(Input {
+                text: fs::read_to_string(path).map_err(|err| AocError::io(path, err))?,
+                checksum: Checksum::NotApplicable,
+            }),
+            InputSource::Stdin => {
+                let mut text = String::new();
+                io::stdin()
+                    .read_to_string(&mut text)
+                    .map_err(|err| AocError::io(Path::new("<stdin>"), err))?;
+
+                Ok(Input {
+                    text,
+                    checksum: Checksum::NotApplicable,
+                })
+            }
+        }
+    }
+
+    /// Stores the checksum of the current input of the given day in the manifest.
+    pub fn record(&self, year: u32, day: u32) -> Result<String, AocError> {
+        let path = self.path(year, day);
+        let text = fs::read_to_string(&path).map_err(|err| AocError::io(&path, err))?;
+        let checksum = checksum(&text);
+
+        let mut manifest = self.read_manifest()?;
+        manifest.insert(relative_path(year, day), checksum.clone());
+
+        let contents: String = manifest
+            .iter()
+            .map(|(file, checksum)| format!("{checksum}  {file}\n"))
+            .collect();
+        let manifest_path = self.root.join(MANIFEST);
+        fs::write(&manifest_path, contents).map_err(|err| AocError::io(&manifest_path, err))?;
+
+        Ok(checksum)
+    }
+
+    fn read_manifest(&self) -> Result<BTreeMap<String, String>, AocError> {
+        let path = self.root.join(MANIFEST);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(err) => return Err(AocError::io(&path, err)),
+        };
+
+        Ok(contents
+            .lines()
+            .filter_map(|line| line.split_once("  "))
+            .map(|(checksum, file)| (file.to_owned(), checksum.to_owned()))
+            .collect())
+    }
+}
+
+fn relative_path(year: u32, day: u32) -> String {
+    format!("{year}/day{day:02}.txt")
+}
+
+/// Brings a puzzle input into the shape every generator expects: no byte order mark, `\n` line
+/// endings, no trailing whitespace on any line and no blank lines or newline at the end. Leading
+/// whitespace is kept, as the crate drawing of day 5 depends on it.
+///
+/// [`Solver::parse`](crate::puzzle::Solver::parse) normalizes before parsing, and so does every
+/// cargo-aoc generator, which gets the input only trimmed of trailing newlines.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let normalized = input
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n");
+    let normalized = normalized.trim_end_matches('\n');
+
+    if normalized == input {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(normalized.to_owned())
+    }
+}
+
+/// Hex encoded SHA-256 of `input`.
+pub fn checksum(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store(name: &str) -> InputStore {
+        let root = std::env::temp_dir().join(format!("aoc-input-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("2022")).unwrap();
+        InputStore::new(root)
+    }
+
+    #[test]
+    fn test_path() {
+        let store = InputStore::new("inputs");
+
+        assert_eq!(store.path(2022, 7), Path::new("inputs/2022/day07.txt"));
+        assert_eq!(store.path(2022, 12), Path::new("inputs/2022/day12.txt"));
+    }
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(
+            checksum("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize("\u{feff}    [D] \r\n[N] [C]\t\r\n\r\n 1   2\r\n\r\n"),
+            "    [D]\n[N] [C]\n\n 1   2"
+        );
+        assert_eq!(normalize("R 4\nU 4\n\n\n"), "R 4\nU 4");
+        assert!(matches!(normalize("R 4\nU 4"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_record_and_detect_change() {
+        let store = temp_store("record");
+        fs::write(store.path(2022, 1), "1000\n2000\n").unwrap();
+
+        let input = store.load(2022, 1, &InputSource::Store).unwrap();
+        assert_eq!(input.text, "1000\n2000\n");
+        assert_eq!(input.checksum, Checksum::Unrecorded);
+
+        let recorded = store.record(2022, 1).unwrap();
+        let input = store.load(2022, 1, &InputSource::Store).unwrap();
+        assert_eq!(input.checksum, Checksum::Matches);
+
+        fs::write(store.path(2022, 1), "1000\n3000\n").unwrap();
+        let input = store.load(2022, 1, &InputSource::Store).unwrap();
+        assert_eq!(
+            input.checksum,
+            Checksum::Changed {
+                recorded,
+                actual: checksum("1000\n3000\n")
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let store = temp_store("missing");
+
+        assert!(matches!(
+            store.load(2022, 25, &InputSource::Store),
+            Err(AocError::Io { .. })
+        ));
+    }
+}
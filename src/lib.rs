@@ -1,17 +1,17 @@
 #[macro_use]
 extern crate aoc_runner_derive;
 
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod geometry;
+pub mod input;
+pub mod parse;
+pub mod puzzle;
+pub mod runner;
+pub mod search;
 
+pub mod y2022;
+
+// cargo-aoc only knows about a single year, see the README for running other years
 aoc_lib! { year = 2022 }
@@ -0,0 +1,466 @@
+//! A small set of parser combinators for the puzzle inputs.
+//!
+//! A parser is any `Fn(&str) -> PResult<T>` which returns the rest of the input together with the
+//! parsed value. Parsers hand out sub-slices of their input where they can, and every failure
+//! carries the slice it failed on, so [`run`] can report the exact line and column.
+
+use crate::error::AocError;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError<'a> {
+    /// The offending part of the input, empty if something is missing.
+    pub span: &'a str,
+    pub reason: String,
+}
+
+impl<'a> ParseError<'a> {
+    pub fn new(span: &'a str, reason: impl Into<String>) -> ParseError<'a> {
+        ParseError {
+            span,
+            reason: reason.into(),
+        }
+    }
+
+    /// Locates the span in `input`, which has to be the text the failed parser was run on.
+    pub fn into_error(self, day: u32, input: &str) -> AocError {
+        AocError::at(day, input, self.span, &self.reason)
+    }
+}
+
+pub type PResult<'a, T> = Result<(&'a str, T), ParseError<'a>>;
+
+pub trait Parser<'a, T> {
+    fn parse(&self, input: &'a str) -> PResult<'a, T>;
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(&'a str) -> PResult<'a, T>,
+{
+    fn parse(&self, input: &'a str) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+/// Parses all of `input` except for trailing whitespace and turns a failure into an [`AocError`]
+/// of the given day.
+pub fn run<'a, T>(day: u32, parser: impl Parser<'a, T>, input: &'a str) -> Result<T, AocError> {
+    complete(&parser, input.trim_end(), "expected end of input")
+        .map_err(|err| err.into_error(day, input))
+}
+
+fn complete<'a, T>(
+    parser: &impl Parser<'a, T>,
+    input: &'a str,
+    reason: &str,
+) -> Result<T, ParseError<'a>> {
+    let (rest, value) = parser.parse(input)?;
+
+    if rest.is_empty() {
+        Ok(value)
+    } else {
+        Err(ParseError::new(until_newline(rest), reason))
+    }
+}
+
+fn until_newline(input: &str) -> &str {
+    input.split('\n').next().unwrap_or_default()
+}
+
+/// Where `rest`, a suffix of `input`, starts within `input`.
+fn consumed<'a>(input: &'a str, rest: &'a str) -> &'a str {
+    &input[..input.len() - rest.len()]
+}
+
+/// Matches `expected` literally.
+pub fn tag<'a>(expected: &'static str) -> impl Fn(&'a str) -> PResult<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(expected) {
+        Some(rest) => Ok((rest, consumed(input, rest))),
+        None => {
+            let line = until_newline(input);
+            if expected.starts_with(line) {
+                return Err(ParseError::new(
+                    &line[line.len()..],
+                    format!("expected `{expected}`"),
+                ));
+            }
+
+            let span_end = line
+                .char_indices()
+                .nth(expected.chars().count())
+                .map_or(line.len(), |(offset, _)| offset);
+
+            Err(ParseError::new(
+                &line[..span_end],
+                format!("expected `{expected}`"),
+            ))
+        }
+    }
+}
+
+/// A single character for which `predicate` holds.
+pub fn satisfy<'a>(
+    predicate: impl Fn(char) -> bool,
+    reason: &'static str,
+) -> impl Fn(&'a str) -> PResult<'a, char> {
+    move |input: &'a str| match input.chars().next() {
+        Some(c) if predicate(c) => Ok((&input[c.len_utf8()..], c)),
+        Some('\n') | None => Err(ParseError::new(&input[..0], reason)),
+        Some(c) => Err(ParseError::new(&input[..c.len_utf8()], reason)),
+    }
+}
+
+/// The longest, possibly empty, prefix whose characters satisfy `predicate`.
+pub fn take_while<'a>(
+    predicate: impl Fn(char) -> bool,
+) -> impl Fn(&'a str) -> PResult<'a, &'a str> {
+    move |input: &'a str| {
+        let end = input
+            .char_indices()
+            .find(|&(_, c)| !predicate(c))
+            .map_or(input.len(), |(offset, _)| offset);
+
+        Ok((&input[end..], &input[..end]))
+    }
+}
+
+/// Like [`take_while`], but fails on an empty match.
+pub fn take_while1<'a>(
+    predicate: impl Fn(char) -> bool,
+    reason: &'static str,
+) -> impl Fn(&'a str) -> PResult<'a, &'a str> {
+    let take = take_while(predicate);
+
+    move |input: &'a str| match take(input)? {
+        (_, "") => Err(ParseError::new(token_at(input), reason)),
+        matched => Ok(matched),
+    }
+}
+
+/// Spaces and tabs, but no line breaks.
+pub fn space0(input: &str) -> PResult<'_, &str> {
+    take_while(|c| c == ' ' || c == '\t')(input)
+}
+
+pub fn space1(input: &str) -> PResult<'_, &str> {
+    take_while1(|c| c == ' ' || c == '\t', "expected a space")(input)
+}
+
+fn token_at(input: &str) -> &str {
+    take_while(|c| !c.is_whitespace())(input).unwrap().1
+}
+
+/// Everything up to the next whitespace.
+pub fn token(input: &str) -> PResult<'_, &str> {
+    take_while1(|c| !c.is_whitespace(), "expected a token")(input)
+}
+
+/// A decimal number, optionally signed. Letters directly attached to the digits count as part of
+/// the number, so `20x0` fails as a whole instead of parsing as `20`.
+pub fn number<T: FromStr>(input: &str) -> PResult<'_, T> {
+    let sign = usize::from(input.starts_with(['-', '+']));
+    let (rest, _) = take_while(|c| c.is_ascii_alphanumeric())(&input[sign..])?;
+    let digits = consumed(input, rest);
+
+    match digits.parse() {
+        Ok(value) => Ok((rest, value)),
+        Err(_) => Err(ParseError::new(digits, "expected a number")),
+    }
+}
+
+pub fn map<'a, A, B>(
+    parser: impl Parser<'a, A>,
+    f: impl Fn(A) -> B,
+) -> impl Fn(&'a str) -> PResult<'a, B> {
+    move |input: &'a str| {
+        let (rest, value) = parser.parse(input)?;
+        Ok((rest, f(value)))
+    }
+}
+
+/// Maps the result of `parser` with a fallible `f`, blaming everything `parser` consumed if `f`
+/// fails.
+pub fn map_res<'a, A, B>(
+    parser: impl Parser<'a, A>,
+    f: impl Fn(A) -> Result<B, &'static str>,
+) -> impl Fn(&'a str) -> PResult<'a, B> {
+    move |input: &'a str| {
+        let (rest, value) = parser.parse(input)?;
+
+        match f(value) {
+            Ok(value) => Ok((rest, value)),
+            Err(reason) => Err(ParseError::new(consumed(input, rest), reason)),
+        }
+    }
+}
+
+/// The part of the input consumed by `parser`.
+pub fn recognize<'a, T>(parser: impl Parser<'a, T>) -> impl Fn(&'a str) -> PResult<'a, &'a str> {
+    move |input: &'a str| {
+        let (rest, _) = parser.parse(input)?;
+        Ok((rest, consumed(input, rest)))
+    }
+}
+
+/// Fails unless `parser` consumes all of the input.
+pub fn all_consuming<'a, T>(parser: impl Parser<'a, T>) -> impl Fn(&'a str) -> PResult<'a, T> {
+    move |input: &'a str| {
+        let value = complete(&parser, input, "unexpected input")?;
+        Ok((&input[input.len()..], value))
+    }
+}
+
+/// Replaces the reason of a failure of `parser`, keeping its span.
+pub fn context<'a, T>(
+    parser: impl Parser<'a, T>,
+    reason: &'static str,
+) -> impl Fn(&'a str) -> PResult<'a, T> {
+    move |input: &'a str| {
+        parser
+            .parse(input)
+            .map_err(|err| ParseError::new(err.span, reason))
+    }
+}
+
+pub fn opt<'a, T>(parser: impl Parser<'a, T>) -> impl Fn(&'a str) -> PResult<'a, Option<T>> {
+    move |input: &'a str| match parser.parse(input) {
+        Ok((rest, value)) => Ok((rest, Some(value))),
+        Err(_) => Ok((input, None)),
+    }
+}
+
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Fn(&'a str) -> PResult<'a, (A, B)> {
+    move |input: &'a str| {
+        let (input, a) = first.parse(input)?;
+        let (input, b) = second.parse(input)?;
+        Ok((input, (a, b)))
+    }
+}
+
+pub fn preceded<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Fn(&'a str) -> PResult<'a, B> {
+    map(pair(first, second), |(_, b)| b)
+}
+
+pub fn terminated<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Fn(&'a str) -> PResult<'a, A> {
+    map(pair(first, second), |(a, _)| a)
+}
+
+pub fn separated_pair<'a, A, S, B>(
+    first: impl Parser<'a, A>,
+    separator: impl Parser<'a, S>,
+    second: impl Parser<'a, B>,
+) -> impl Fn(&'a str) -> PResult<'a, (A, B)> {
+    pair(terminated(first, separator), second)
+}
+
+pub fn delimited<'a, A, B, C>(
+    open: impl Parser<'a, A>,
+    parser: impl Parser<'a, B>,
+    close: impl Parser<'a, C>,
+) -> impl Fn(&'a str) -> PResult<'a, B> {
+    preceded(open, terminated(parser, close))
+}
+
+/// A choice between parsers, see [`alt`].
+pub trait Alt<'a, T> {
+    fn choice(&self, input: &'a str) -> PResult<'a, T>;
+}
+
+macro_rules! alt_tuple {
+    ($($parser:ident $index:tt),+) => {
+        impl<'a, T, $($parser: Parser<'a, T>),+> Alt<'a, T> for ($($parser,)+) {
+            fn choice(&self, input: &'a str) -> PResult<'a, T> {
+                let mut furthest: Option<ParseError<'a>> = None;
+
+                $(
+                    match self.$index.parse(input) {
+                        Ok(parsed) => return Ok(parsed),
+                        Err(err) => {
+                            if furthest
+                                .as_ref()
+                                .is_none_or(|other| err.span.as_ptr() >= other.span.as_ptr())
+                            {
+                                furthest = Some(err);
+                            }
+                        }
+                    }
+                )+
+
+                Err(furthest.unwrap())
+            }
+        }
+    };
+}
+
+alt_tuple!(A 0, B 1);
+alt_tuple!(A 0, B 1, C 2);
+alt_tuple!(A 0, B 1, C 2, D 3);
+alt_tuple!(A 0, B 1, C 2, D 3, E 4);
+
+/// Tries the parsers of a tuple in order. If all of them fail, the failure which got furthest
+/// into the input is reported.
+pub fn alt<'a, T>(parsers: impl Alt<'a, T>) -> impl Fn(&'a str) -> PResult<'a, T> {
+    move |input: &'a str| parsers.choice(input)
+}
+
+/// One or more `parser`s separated by `separator`. Once a separator matched, the following
+/// element is mandatory.
+pub fn separated_list1<'a, S, T>(
+    separator: impl Parser<'a, S>,
+    parser: impl Parser<'a, T>,
+) -> impl Fn(&'a str) -> PResult<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (mut input, first) = parser.parse(input)?;
+        let mut values = vec![first];
+
+        while let Ok((rest, _)) = separator.parse(input) {
+            let (rest, value) = parser.parse(rest)?;
+            values.push(value);
+            input = rest;
+        }
+
+        Ok((input, values))
+    }
+}
+
+/// Applies `parser` until the input is used up, which makes every failure of it an error.
+pub fn many_to_end<'a, T>(parser: impl Parser<'a, T>) -> impl Fn(&'a str) -> PResult<'a, Vec<T>> {
+    move |mut input: &'a str| {
+        let mut values = Vec::new();
+
+        while !input.is_empty() {
+            let (rest, value) = parser.parse(input)?;
+            if rest.len() == input.len() {
+                return Err(ParseError::new(
+                    until_newline(input),
+                    "parser made no progress",
+                ));
+            }
+
+            values.push(value);
+            input = rest;
+        }
+
+        Ok((input, values))
+    }
+}
+
+fn split_by<'a, T>(
+    input: &'a str,
+    separator: &'static str,
+    parser: &impl Parser<'a, T>,
+) -> PResult<'a, Vec<T>> {
+    if input.is_empty() {
+        return Ok((input, Vec::new()));
+    }
+
+    let values = input
+        .split(separator)
+        .map(|part| complete(parser, part, "expected end of line"))
+        .collect::<Result<_, _>>()?;
+
+    Ok((&input[input.len()..], values))
+}
+
+/// Parses every line of the input with `parser`, which has to consume the line completely.
+pub fn lines<'a, T>(parser: impl Parser<'a, T>) -> impl Fn(&'a str) -> PResult<'a, Vec<T>> {
+    move |input: &'a str| split_by(input, "\n", &parser)
+}
+
+/// Parses every group of lines separated by a blank line with `parser`, which has to consume
+/// the group completely.
+pub fn blocks<'a, T>(parser: impl Parser<'a, T>) -> impl Fn(&'a str) -> PResult<'a, Vec<T>> {
+    move |input: &'a str| split_by(input, "\n\n", &parser)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence() {
+        let parser = pair(
+            preceded(tag("move "), number::<u32>),
+            preceded(tag(" from "), number::<u32>),
+        );
+
+        assert_eq!(parser("move 3 from 1 to 2"), Ok((" to 2", (3, 1))));
+        assert_eq!(
+            parser("move 3 to 1"),
+            Err(ParseError::new(" to 1", "expected ` from `"))
+        );
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number::<i32>("-11 rest"), Ok((" rest", -11)));
+        assert_eq!(
+            number::<i32>("x=2"),
+            Err(ParseError::new("x", "expected a number"))
+        );
+        assert_eq!(
+            number::<u32>("20x0\n5"),
+            Err(ParseError::new("20x0", "expected a number"))
+        );
+    }
+
+    #[test]
+    fn test_alt_reports_furthest_failure() {
+        let slot = alt((
+            map(
+                delimited(
+                    tag("["),
+                    satisfy(char::is_alphabetic, "expected a crate"),
+                    tag("]"),
+                ),
+                Some,
+            ),
+            map(tag("   "), |_| None),
+        ));
+
+        assert_eq!(slot("[D] [N]"), Ok((" [N]", Some('D'))));
+        assert_eq!(slot("    [N]"), Ok((" [N]", None)));
+        assert_eq!(slot("[1]"), Err(ParseError::new("1", "expected a crate")));
+    }
+
+    #[test]
+    fn test_lines_are_zero_copy() {
+        let input = "ab cd\nef gh";
+        let (_, words) = lines(separated_list1(space1, token))(input).unwrap();
+
+        assert_eq!(words, vec![vec!["ab", "cd"], vec!["ef", "gh"]]);
+        assert_eq!(words[1][0].as_ptr(), input[6..].as_ptr());
+    }
+
+    #[test]
+    fn test_run_points_at_span() {
+        let row = many_to_end(satisfy(|c| c.is_ascii_digit(), "expected a digit"));
+
+        assert_eq!(
+            run(8, lines(row), "303\n2x5\n653\n")
+                .unwrap_err()
+                .to_string(),
+            "day 8, line 2, column 2: expected a digit, found `x`"
+        );
+        assert_eq!(
+            run(1, blocks(lines(number::<u32>)), "1\n2\n\n3\n").unwrap(),
+            vec![vec![1, 2], vec![3]]
+        );
+        assert_eq!(
+            run(5, lines(tag("move")), "move\nmove 1")
+                .unwrap_err()
+                .to_string(),
+            "day 5, line 2, column 5: expected end of line, found ` 1`"
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+
+/// The result of a single part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(number: $number) -> Answer {
+                    Answer::Number(number as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_owned())
+    }
+}
+
+/// A part that found no solution for its input says so, rather than giving a made up number.
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Answer {
+        answer.map_or_else(|| Answer::from("no solution"), Into::into)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(part: u32) -> Option<Part> {
+        match part {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A day of Advent of Code: parses the puzzle input once and solves both parts from it.
+pub trait Puzzle {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError>;
+
+    fn part1(&self, input: &Self::Input) -> Answer;
+
+    fn part2(&self, input: &Self::Input) -> Answer;
+}
+
+/// Object safe view of a [`Puzzle`], so puzzles with different input types can be stored and
+/// called side by side. The parsed input is passed around as [`Any`].
+pub trait Solver {
+    /// Parses the [`normalize`]d `input`.
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, AocError>;
+
+    /// Solves `part` for an input returned by [`Solver::parse`] of the same solver.
+    fn solve_parsed(&self, part: Part, input: &dyn Any) -> Answer;
+
+    fn solve(&self, part: Part, input: &str) -> Result<Answer, AocError> {
+        Ok(self.solve_parsed(part, self.parse(input)?.as_ref()))
+    }
+}
+
+impl<P> Solver for P
+where
+    P: Puzzle,
+    P::Input: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, AocError> {
+        Ok(Box::new(Puzzle::parse(self, &normalize(input))?))
+    }
+
+    fn solve_parsed(&self, part: Part, input: &dyn Any) -> Answer {
+        let input = input
+            .downcast_ref::<P::Input>()
+            .expect("input should be parsed by the same puzzle");
+
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Puzzle for Sum {
+        type Input = Vec<u32>;
+
+        fn parse(&self, input: &str) -> Result<Vec<u32>, AocError> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(index, line)| {
+                    line.parse()
+                        .map_err(|_| AocError::parse(0, index + 1, line, line, "expected a number"))
+                })
+                .collect()
+        }
+
+        fn part1(&self, input: &Vec<u32>) -> Answer {
+            input.iter().sum::<u32>().into()
+        }
+
+        fn part2(&self, input: &Vec<u32>) -> Answer {
+            format!("{} numbers", input.len()).into()
+        }
+    }
+
+    #[test]
+    fn test_solver() {
+        let solver: Box<dyn Solver> = Box::new(Sum);
+
+        assert_eq!(
+            solver.solve(Part::One, "1\n2\n3").unwrap(),
+            Answer::Number(6)
+        );
+        assert_eq!(
+            solver.solve(Part::Two, "1\n2\n3").unwrap().to_string(),
+            "3 numbers"
+        );
+        assert!(solver.solve(Part::One, "1\nx").is_err());
+        assert_eq!(
+            solver
+                .solve(Part::Two, "\u{feff}1\r\n2 \r\n3\r\n\r\n")
+                .unwrap()
+                .to_string(),
+            "3 numbers"
+        );
+    }
+
+    #[test]
+    fn test_missing_answer() {
+        assert_eq!(Answer::from(Some(42)), Answer::Number(42));
+        assert_eq!(Answer::from(None::<u32>).to_string(), "no solution");
+    }
+
+    #[test]
+    fn test_part_numbers() {
+        assert_eq!(Part::from_number(2), Some(Part::Two));
+        assert_eq!(Part::from_number(3), None);
+        assert_eq!(Part::One.number(), 1);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::error::AocError;
+use crate::puzzle::{Answer, Part, Puzzle, Solver};
+use crate::y2022;
+use std::collections::BTreeMap;
+
+/// Maps `(year, day)` to the solver of that day.
+#[derive(Default)]
+pub struct Registry {
+    solvers: BTreeMap<(u32, u32), Box<dyn Solver>>,
+}
+
+impl Registry {
+    pub fn register<P>(&mut self, year: u32, day: u32, puzzle: P)
+    where
+        P: Puzzle + 'static,
+        P::Input: 'static,
+    {
+        self.solvers.insert((year, day), Box::new(puzzle));
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Option<&dyn Solver> {
+        self.solvers.get(&(year, day)).map(Box::as_ref)
+    }
+
+    /// All registered solvers ordered by year and day.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, u32, &dyn Solver)> {
+        self.solvers
+            .iter()
+            .map(|(&(year, day), solver)| (year, day, solver.as_ref()))
+    }
+
+    /// The years with at least one registered solver, in ascending order.
+    pub fn years(&self) -> Vec<u32> {
+        let mut years: Vec<u32> = self.solvers.keys().map(|&(year, _)| year).collect();
+        years.dedup();
+        years
+    }
+
+    pub fn solve(&self, year: u32, day: u32, part: u32, input: &str) -> Result<Answer, AocError> {
+        let unknown = AocError::UnknownPuzzle { day, part };
+        let solver = self.get(year, day).ok_or(unknown.clone())?;
+
+        solver.solve(Part::from_number(part).ok_or(unknown)?, input)
+    }
+}
+
+/// Registration functions of every year in this crate, one entry per
+/// `yNNNN` module.
+const YEARS: &[fn(&mut Registry)] = &[y2022::register];
+
+/// Every solution in this crate.
+pub fn registry() -> Registry {
+    let mut registry = Registry::default();
+
+    for register in YEARS {
+        register(&mut registry);
+    }
+
+    registry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_solve() {
+        let answer = registry()
+            .solve(2022, 6, 1, "bvwbjplbgvbhsrlpgdmjqwftvncz")
+            .unwrap();
+
+        assert_eq!(answer, Answer::Number(5));
+    }
+
+    #[test]
+    fn test_registry_unknown() {
+        assert_eq!(
+            registry().solve(2022, 26, 1, "").unwrap_err(),
+            AocError::UnknownPuzzle { day: 26, part: 1 }
+        );
+        assert_eq!(
+            registry().solve(2022, 1, 3, "").unwrap_err(),
+            AocError::UnknownPuzzle { day: 1, part: 3 }
+        );
+    }
+
+    #[test]
+    fn test_registry_has_all_days() {
+        let days: Vec<u32> = registry().iter().map(|(_, day, _)| day).collect();
+
+        assert_eq!(days, (1..=25).collect::<Vec<u32>>());
+        assert_eq!(registry().years(), vec![y2022::YEAR]);
+    }
+}
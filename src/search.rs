@@ -0,0 +1,51 @@
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Searches breadth-first from `start` for the nearest state that `is_goal` accepts, returning it
+/// with the number of steps to it, or `None` if no goal can be reached.
+///
+/// States with the same `key` are only visited once. The key is usually the state itself, but it
+/// can leave out whatever doesn't change where the search may go from a state.
+pub fn bfs<S, K, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut key: impl FnMut(&S) -> K,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(S, usize)>
+where
+    K: Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = HashSet::from([key(&start)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, steps)) = queue.pop_front() {
+        if is_goal(&state) {
+            return Some((state, steps));
+        }
+
+        for next in neighbors(&state) {
+            if visited.insert(key(&next)) {
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bfs() {
+        // the shortest way from 1 to 10 by adding one or doubling
+        let steps = |&n: &u32| [n + 1, 2 * n].into_iter().filter(|&next| next <= 10);
+
+        assert_eq!(bfs(1, steps, |&n| n, |&n| n == 10), Some((10, 4)));
+        assert_eq!(bfs(1, steps, |&n| n, |&n| n == 11), None);
+        // keyed on parity, only one odd and one even number are ever visited
+        assert_eq!(bfs(1, steps, |&n| n % 2, |&n| n == 10), None);
+    }
+}
@@ -0,0 +1,578 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{self, blocks, context, lines, number, PResult, ParseError};
+use crate::puzzle::{Answer, Puzzle};
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::io::BufRead;
+use std::path::Path;
+
+const CALORIE_COUNT: &str = "expected a calorie count";
+/// The length of the bar of the largest bucket when rendering a histogram.
+const HISTOGRAM_WIDTH: usize = 40;
+
+#[aoc_generator(day1)]
+pub fn parse_input(input: &str) -> Result<Vec<Vec<u32>>, AocError> {
+    let input = &normalize(input);
+
+    parse::run(1, blocks(inventory), input)
+}
+
+/// The calories of one elf, one per line and at least one of them, so that blank lines can only
+/// separate two inventories.
+fn inventory(input: &str) -> PResult<'_, Vec<u32>> {
+    if input.is_empty() {
+        return Err(ParseError::new(input, CALORIE_COUNT));
+    }
+
+    lines(context(number, CALORIE_COUNT))(input)
+}
+
+/// The total calories of each elf, or `None` for an elf whose total doesn't fit in a `u32`.
+fn elf_totals(calories: &[Vec<u32>]) -> impl Iterator<Item = Option<u32>> + '_ {
+    calories.iter().map(|inventory| {
+        inventory
+            .iter()
+            .try_fold(0u32, |total, &calories| total.checked_add(calories))
+    })
+}
+
+/// The sum of the `k` largest totals, or `None` if a total or the sum doesn't fit in a `u32`,
+/// where [`Totals`] stops with an error instead.
+fn top_k_sum(calories: &[Vec<u32>], k: usize) -> Option<u32> {
+    let totals = elf_totals(calories).collect::<Option<Vec<u32>>>()?;
+    if totals.is_empty() {
+        return None;
+    }
+
+    top_k(totals, k)
+        .into_iter()
+        .try_fold(0u32, |sum, total| sum.checked_add(total))
+}
+
+/// The `k` largest of `totals`, largest first. Only `k` totals are kept at any time, so this
+/// works just as well on a stream of totals that doesn't fit in memory.
+pub fn top_k(totals: impl IntoIterator<Item = u32>, k: usize) -> Vec<u32> {
+    // a min-heap, so the smallest of the top totals is the one to drop
+    let mut top = BinaryHeap::with_capacity(k + 1);
+
+    for total in totals {
+        top.push(Reverse(total));
+        if top.len() > k {
+            top.pop();
+        }
+    }
+
+    top.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(total)| total)
+        .collect()
+}
+
+/// The total calories of each elf, summed while reading `reader` line by line so that no
+/// inventory is ever stored. Accepts the same input as [`parse_input`], normalized line by line
+/// the way [`normalize`](crate::input::normalize) does it for the whole input.
+pub struct Totals<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    /// The first blank line that didn't follow an inventory, which is only an error if another
+    /// inventory comes after it, as trailing blank lines are fine.
+    stray_blank_line: Option<usize>,
+    done: bool,
+}
+
+impl<R: BufRead> Totals<R> {
+    pub fn new(reader: R) -> Totals<R> {
+        Totals {
+            reader,
+            line: String::new(),
+            line_number: 0,
+            stray_blank_line: None,
+            done: false,
+        }
+    }
+
+    /// Reads the next inventory, returning `None` at the end of the input.
+    fn next_total(&mut self) -> Result<Option<u32>, AocError> {
+        let mut total = None;
+
+        loop {
+            self.line.clear();
+            let read = self
+                .reader
+                .read_line(&mut self.line)
+                .map_err(|err| AocError::io(Path::new("<input>"), err))?;
+            if read == 0 {
+                return Ok(total);
+            }
+            self.line_number += 1;
+
+            let mut line = self.line.trim_end();
+            if self.line_number == 1 {
+                line = line.strip_prefix('\u{feff}').unwrap_or(line);
+            }
+            if line.is_empty() {
+                if total.is_some() {
+                    return Ok(total);
+                }
+                self.stray_blank_line.get_or_insert(self.line_number);
+                continue;
+            }
+            if let Some(line_number) = self.stray_blank_line {
+                return Err(AocError::parse(1, line_number, "", "", CALORIE_COUNT));
+            }
+
+            let error = |reason| AocError::parse(1, self.line_number, line, line, reason);
+            let calories: u32 = line.parse().map_err(|_| error(CALORIE_COUNT))?;
+            total = Some(
+                total
+                    .unwrap_or(0)
+                    .checked_add(calories)
+                    .ok_or_else(|| error("too many calories for one elf"))?,
+            );
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Totals<R> {
+    type Item = Result<u32, AocError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let total = self.next_total().transpose();
+        // stop after the first error rather than resuming in the middle of an inventory
+        self.done = matches!(total, None | Some(Err(_)));
+        total
+    }
+}
+
+/// The `k` largest totals of the inventories read from `reader`, largest first, in memory
+/// bounded by `k` rather than by the size of the input.
+pub fn stream_top_k(reader: impl BufRead, k: usize) -> Result<Vec<u32>, AocError> {
+    let mut error = None;
+    let top = top_k(
+        Totals::new(reader).map_while(|total| total.map_err(|err| error = Some(err)).ok()),
+        k,
+    );
+
+    error.map_or(Ok(top), Err)
+}
+
+/// An elf's place in a [`Report`], with elves numbered from 1 in the order of the input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Ranked {
+    pub elf: usize,
+    pub items: usize,
+    pub total: u64,
+}
+
+/// The elves whose totals are in `from..=to`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Bucket {
+    pub from: u64,
+    pub to: u64,
+    pub elves: usize,
+}
+
+/// Statistics about the totals of all elves, renders as a table or as JSON.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    /// The elves by total, largest first and by elf number on ties.
+    pub ranking: Vec<Ranked>,
+    pub mean: f64,
+    pub median: f64,
+    /// The population standard deviation.
+    pub std_dev: f64,
+    pub histogram: Vec<Bucket>,
+    /// The elves whose totals don't fit in the `u32` that [`part1`] and [`part2`] sum into.
+    pub u32_overflows: Vec<usize>,
+}
+
+impl Report {
+    /// Builds the report for the inventories returned by [`parse_input`], with the totals
+    /// split into at most `buckets` buckets of equal width. Fails if there are no elves, or if the
+    /// total of an elf, or of all elves up to it, doesn't fit in a `u64`.
+    pub fn new(calories: &[Vec<u32>], buckets: usize) -> Result<Report, AocError> {
+        let mut ranking = Vec::with_capacity(calories.len());
+        let mut sum: u64 = 0;
+
+        for (index, inventory) in calories.iter().enumerate() {
+            let elf = index + 1;
+            let overflow = || AocError::Unusable {
+                day: 1,
+                reason: format!("calories overflow at elf {elf}"),
+            };
+            let total = inventory
+                .iter()
+                .try_fold(0u64, |total, &calories| total.checked_add(calories.into()))
+                .ok_or_else(overflow)?;
+            sum = sum.checked_add(total).ok_or_else(overflow)?;
+
+            ranking.push(Ranked {
+                elf,
+                items: inventory.len(),
+                total,
+            });
+        }
+        if ranking.is_empty() {
+            return Err(AocError::Unusable {
+                day: 1,
+                reason: "no elves to report on".to_owned(),
+            });
+        }
+
+        let u32_overflows = ranking
+            .iter()
+            .filter(|ranked| ranked.total > u32::MAX.into())
+            .map(|ranked| ranked.elf)
+            .collect();
+
+        let count = ranking.len() as f64;
+        let mean = sum as f64 / count;
+        let variance = ranking
+            .iter()
+            .map(|ranked| (ranked.total as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        ranking.sort_by_key(|ranked| (Reverse(ranked.total), ranked.elf));
+        let middle = ranking.len() / 2;
+        let median = if ranking.len() % 2 == 1 {
+            ranking[middle].total as f64
+        } else {
+            (ranking[middle - 1].total as f64 + ranking[middle].total as f64) / 2.0
+        };
+        let histogram = histogram(&ranking, buckets);
+
+        Ok(Report {
+            ranking,
+            mean,
+            median,
+            std_dev: variance.sqrt(),
+            histogram,
+            u32_overflows,
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("report should serialize")
+    }
+}
+
+/// Splits the range of totals of `ranking`, which is sorted largest first, into buckets of
+/// equal width. The last bucket ends at the largest total and may be a bit wider.
+fn histogram(ranking: &[Ranked], buckets: usize) -> Vec<Bucket> {
+    let max = ranking[0].total;
+    let min = ranking[ranking.len() - 1].total;
+    let buckets = buckets.max(1) as u64;
+    let width = (max - min).div_ceil(buckets).max(1);
+    let count = ((max - min) / width + 1).min(buckets);
+
+    let mut histogram: Vec<Bucket> = (0..count)
+        .map(|bucket| {
+            let from = min + bucket * width;
+            let to = if bucket + 1 == count {
+                max
+            } else {
+                from + width - 1
+            };
+            Bucket { from, to, elves: 0 }
+        })
+        .collect();
+    for ranked in ranking {
+        let bucket = ((ranked.total - min) / width).min(count - 1);
+        histogram[bucket as usize].elves += 1;
+    }
+
+    histogram
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>4}  {:>5}  {:>5}  {:>10}",
+            "rank", "elf", "items", "total"
+        )?;
+        for (rank, ranked) in self.ranking.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>4}  {:>5}  {:>5}  {:>10}",
+                rank + 1,
+                ranked.elf,
+                ranked.items,
+                ranked.total
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "mean {:.1}", self.mean)?;
+        writeln!(f, "median {:.1}", self.median)?;
+        writeln!(f, "standard deviation {:.1}", self.std_dev)?;
+
+        writeln!(f)?;
+        let most = self.histogram.iter().map(|bucket| bucket.elves).max();
+        for bucket in &self.histogram {
+            let bar = "#".repeat((bucket.elves * HISTOGRAM_WIDTH).div_ceil(most.unwrap_or(1)));
+            let line = format!(
+                "{:>10} - {:>10}  {:>5}  {bar}",
+                bucket.from, bucket.to, bucket.elves
+            );
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        if !self.u32_overflows.is_empty() {
+            let elves: Vec<String> = self.u32_overflows.iter().map(usize::to_string).collect();
+            writeln!(f)?;
+            writeln!(f, "totals overflowing u32: elves {}", elves.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[aoc(day1, part1)]
+pub(crate) fn part1(calories: &[Vec<u32>]) -> Option<u32> {
+    top_k_sum(calories, 1)
+}
+
+#[aoc(day1, part2)]
+pub(crate) fn part2(calories: &[Vec<u32>]) -> Option<u32> {
+    top_k_sum(calories, 3)
+}
+
+pub struct Day01;
+
+impl Puzzle for Day01 {
+    type Input = Vec<Vec<u32>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, calories: &Self::Input) -> Answer {
+        part1(calories).into()
+    }
+
+    fn part2(&self, calories: &Self::Input) -> Answer {
+        part2(calories).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r"1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), Some(24000));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), Some(45000));
+    }
+
+    #[test]
+    fn top_k_totals() {
+        let calories = parse_input(TEST_INPUT).unwrap();
+        let totals: Vec<u32> = elf_totals(&calories).map(Option::unwrap).collect();
+
+        assert_eq!(top_k(totals.clone(), 3), vec![24000, 11000, 10000]);
+        assert_eq!(top_k(totals.clone(), 0), Vec::<u32>::new());
+        assert_eq!(top_k(totals, 9), vec![24000, 11000, 10000, 6000, 4000]);
+    }
+
+    #[test]
+    fn stream_example() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n\r\n";
+        let totals: Result<Vec<u32>, AocError> = Totals::new(input.as_bytes()).collect();
+
+        assert_eq!(totals.unwrap(), vec![6000, 4000, 11000, 24000, 10000]);
+        assert_eq!(
+            stream_top_k(TEST_INPUT.as_bytes(), 3).unwrap(),
+            vec![24000, 11000, 10000]
+        );
+    }
+
+    #[test]
+    fn stream_normalizes_like_parse_input() {
+        let input = "\u{feff}1000 \r\n2000\t\r\n  \r\n4000\r\n\r\n\r\n";
+        let streamed = stream_top_k(input.as_bytes(), 3).unwrap();
+
+        assert_eq!(streamed, vec![4000, 3000]);
+        assert_eq!(
+            Day01.solve(Part::One, input).unwrap(),
+            Answer::Number(streamed[0].into())
+        );
+        assert_eq!(
+            Day01.solve(Part::Two, input).unwrap(),
+            Answer::Number(streamed.iter().sum::<u32>().into())
+        );
+    }
+
+    #[test]
+    fn stray_blank_lines() {
+        for (input, line) in [
+            ("1000\n\n\n2000", 3),
+            ("\n1000\n\n2000", 1),
+            ("\n\n1000", 1),
+            ("1000\n\n \n2000", 3),
+        ] {
+            let error = format!(
+                "day 1, line {line}, column 1: expected a calorie count, found end of line"
+            );
+
+            assert_eq!(parse_input(input).unwrap_err().to_string(), error);
+            assert_eq!(
+                stream_top_k(input.as_bytes(), 3).unwrap_err().to_string(),
+                error
+            );
+        }
+
+        let input = "1000\n\n2000\n\n\n";
+        assert_eq!(parse_input(input).unwrap(), vec![vec![1000], vec![2000]]);
+        assert_eq!(stream_top_k(input.as_bytes(), 3).unwrap(), vec![2000, 1000]);
+    }
+
+    #[test]
+    fn no_elves() {
+        let calories = parse_input("").unwrap();
+
+        assert_eq!(part1(&calories), None);
+        assert_eq!(part2(&calories), None);
+        assert_eq!(stream_top_k("".as_bytes(), 3).unwrap(), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn stream_invalid_calories() {
+        assert_eq!(
+            stream_top_k("1000\n\n20x0\n5".as_bytes(), 3)
+                .unwrap_err()
+                .to_string(),
+            "day 1, line 3, column 1: expected a calorie count, found `20x0`"
+        );
+        assert_eq!(
+            stream_top_k("4000000000\n4000000000".as_bytes(), 1)
+                .unwrap_err()
+                .to_string(),
+            "day 1, line 2, column 1: too many calories for one elf, found `4000000000`"
+        );
+    }
+
+    #[test]
+    fn u32_overflow() {
+        for input in ["4294967295\n1", "4294967295\n\n1"] {
+            let calories = parse_input(input).unwrap();
+
+            assert_eq!(part2(&calories), None);
+            assert_eq!(
+                Day01.solve(Part::Two, input).unwrap(),
+                Answer::Text("no solution".to_owned())
+            );
+        }
+        assert_eq!(part1(&parse_input("4294967295\n1").unwrap()), None);
+        assert!(stream_top_k("4294967295\n1".as_bytes(), 1).is_err());
+        assert_eq!(
+            part1(&parse_input("4294967295\n\n1").unwrap()),
+            Some(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn report_example() {
+        let report = Report::new(&parse_input(TEST_INPUT).unwrap(), 4).unwrap();
+
+        assert_eq!(
+            report.to_string(),
+            "rank    elf  items       total
+   1      4      3       24000
+   2      3      2       11000
+   3      5      1       10000
+   4      1      3        6000
+   5      2      1        4000
+
+mean 11000.0
+median 10000.0
+standard deviation 6985.7
+
+      4000 -       8999      2  ########################################
+      9000 -      13999      2  ########################################
+     14000 -      18999      0
+     19000 -      24000      1  ####################
+"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["ranking"][0]["elf"], 4);
+        assert_eq!(json["median"], 10000.0);
+        assert_eq!(json["histogram"][3]["to"], 24000);
+    }
+
+    #[test]
+    fn report_u32_overflow() {
+        let report = Report::new(&[vec![5], vec![u32::MAX, 1], vec![u32::MAX]], 2).unwrap();
+
+        assert_eq!(report.u32_overflows, vec![2]);
+        assert_eq!(report.ranking[0].total, u64::from(u32::MAX) + 1);
+        assert_eq!(report.median, f64::from(u32::MAX));
+        assert!(report
+            .to_string()
+            .ends_with("totals overflowing u32: elves 2\n"));
+        assert_eq!(
+            Report::new(&[], 2).unwrap_err().to_string(),
+            "day 1: no elves to report on"
+        );
+    }
+
+    #[test]
+    fn parse_input_invalid_calories() {
+        assert_eq!(
+            parse_input("1000\n\n20x0").unwrap_err().to_string(),
+            "day 1, line 3, column 1: expected a calorie count, found `20x0`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(
+            Day01.solve(Part::One, &input).unwrap(),
+            Answer::Number(24000)
+        );
+        assert_eq!(
+            Day01.solve(Part::Two, &input).unwrap(),
+            Answer::Number(45000)
+        );
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), Some(24000));
+        assert_eq!(part2(&parse_input(&input).unwrap()), Some(45000));
+        assert_eq!(
+            part1(&parse_input(&format!("\u{feff}{input}")).unwrap()),
+            Some(24000)
+        );
+    }
+}
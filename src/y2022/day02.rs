@@ -0,0 +1,253 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{self, lines, map_res, space1, token, PResult};
+use crate::puzzle::{Answer, Puzzle};
+
+enum Strategy {
+    Scissor,
+    Paper,
+    Rock,
+}
+
+impl Strategy {
+    fn counter_strategy(&self, outcome: &GameOutcome) -> Strategy {
+        match outcome {
+            GameOutcome::Lose => match &self {
+                Strategy::Scissor => Strategy::Paper,
+                Strategy::Paper => Strategy::Rock,
+                Strategy::Rock => Strategy::Scissor,
+            },
+
+            GameOutcome::Draw => match &self {
+                Strategy::Scissor => Strategy::Scissor,
+                Strategy::Paper => Strategy::Paper,
+                Strategy::Rock => Strategy::Rock,
+            },
+
+            GameOutcome::Win => match &self {
+                Strategy::Scissor => Strategy::Rock,
+                Strategy::Paper => Strategy::Scissor,
+                Strategy::Rock => Strategy::Paper,
+            },
+        }
+    }
+}
+
+enum GameOutcome {
+    Win,
+    Lose,
+    Draw,
+}
+
+pub struct Duel {
+    elf_strategy: Strategy,
+    own_strategy: Strategy,
+}
+
+pub struct DuelWithOutcome {
+    elf_strategy: Strategy,
+    outcome: GameOutcome,
+}
+
+const LOST_GAME_POINTS: u32 = 0;
+const DRAW_GAME_POINTS: u32 = 3;
+const WON_GAME_POINTS: u32 = 6;
+
+const ROCK_POINTS: u32 = 1;
+const PAPER_POINTS: u32 = 2;
+const SCISSOR_POINTS: u32 = 3;
+
+fn elf_strategy(input: &str) -> PResult<'_, Strategy> {
+    map_res(token, |elf_raw| match elf_raw {
+        "A" => Ok(Strategy::Rock),
+        "B" => Ok(Strategy::Paper),
+        "C" => Ok(Strategy::Scissor),
+        _ => Err("unknown strategy"),
+    })(input)
+}
+
+fn own_strategy(input: &str) -> PResult<'_, Strategy> {
+    map_res(token, |own_raw| match own_raw {
+        "X" => Ok(Strategy::Rock),
+        "Y" => Ok(Strategy::Paper),
+        "Z" => Ok(Strategy::Scissor),
+        _ => Err("unknown strategy"),
+    })(input)
+}
+
+fn outcome(input: &str) -> PResult<'_, GameOutcome> {
+    map_res(token, |outcome_raw| match outcome_raw {
+        "X" => Ok(GameOutcome::Lose),
+        "Y" => Ok(GameOutcome::Draw),
+        "Z" => Ok(GameOutcome::Win),
+        _ => Err("unknown strategy"),
+    })(input)
+}
+
+impl Duel {
+    fn parse(input: &str) -> PResult<'_, Duel> {
+        let (input, elf_strategy) = elf_strategy(input)?;
+        let (input, _) = space1(input)?;
+        let (input, own_strategy) = own_strategy(input)?;
+
+        Ok((
+            input,
+            Duel {
+                elf_strategy,
+                own_strategy,
+            },
+        ))
+    }
+
+    fn play(&self) -> u32 {
+        match &self.own_strategy {
+            Strategy::Scissor => {
+                SCISSOR_POINTS
+                    + match &self.elf_strategy {
+                        Strategy::Scissor => DRAW_GAME_POINTS,
+                        Strategy::Paper => WON_GAME_POINTS,
+                        Strategy::Rock => LOST_GAME_POINTS,
+                    }
+            }
+            Strategy::Paper => {
+                PAPER_POINTS
+                    + match &self.elf_strategy {
+                        Strategy::Scissor => LOST_GAME_POINTS,
+                        Strategy::Paper => DRAW_GAME_POINTS,
+                        Strategy::Rock => WON_GAME_POINTS,
+                    }
+            }
+            Strategy::Rock => {
+                ROCK_POINTS
+                    + match &self.elf_strategy {
+                        Strategy::Scissor => WON_GAME_POINTS,
+                        Strategy::Paper => LOST_GAME_POINTS,
+                        Strategy::Rock => DRAW_GAME_POINTS,
+                    }
+            }
+        }
+    }
+}
+
+impl DuelWithOutcome {
+    fn parse(input: &str) -> PResult<'_, DuelWithOutcome> {
+        let (input, elf_strategy) = elf_strategy(input)?;
+        let (input, _) = space1(input)?;
+        let (input, outcome) = outcome(input)?;
+
+        Ok((
+            input,
+            DuelWithOutcome {
+                elf_strategy,
+                outcome,
+            },
+        ))
+    }
+
+    fn play(&self) -> u32 {
+        let own_strategy: Strategy = self.elf_strategy.counter_strategy(&self.outcome);
+
+        let elf_strategy = match &self.elf_strategy {
+            Strategy::Scissor => Strategy::Scissor,
+            Strategy::Paper => Strategy::Paper,
+            Strategy::Rock => Strategy::Rock,
+        };
+
+        let duel = Duel {
+            elf_strategy,
+            own_strategy,
+        };
+        duel.play()
+    }
+}
+
+#[aoc_generator(day2, part1)]
+pub fn parse_input(input: &str) -> Result<Vec<Duel>, AocError> {
+    let input = &normalize(input);
+
+    parse::run(2, lines(Duel::parse), input)
+}
+
+#[aoc_generator(day2, part2)]
+pub fn parse_input_2(input: &str) -> Result<Vec<DuelWithOutcome>, AocError> {
+    let input = &normalize(input);
+
+    parse::run(2, lines(DuelWithOutcome::parse), input)
+}
+
+#[aoc(day2, part1)]
+pub(crate) fn part1(duels: &[Duel]) -> u32 {
+    duels.iter().map(|duel| duel.play()).sum()
+}
+
+#[aoc(day2, part2)]
+pub(crate) fn part2(duels: &[DuelWithOutcome]) -> u32 {
+    duels.iter().map(|duel| duel.play()).sum()
+}
+
+pub struct Day02;
+
+impl Puzzle for Day02 {
+    /// The guide read as own strategies for part 1 and as outcomes for part 2.
+    type Input = (Vec<Duel>, Vec<DuelWithOutcome>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok((parse_input(input)?, parse_input_2(input)?))
+    }
+
+    fn part1(&self, (duels, _): &Self::Input) -> Answer {
+        part1(duels).into()
+    }
+
+    fn part2(&self, (_, duels): &Self::Input) -> Answer {
+        part2(duels).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    #[test]
+    fn test_duel_1() {
+        let (_, duel) = Duel::parse("A Y").unwrap();
+        assert_eq!(duel.play(), 8);
+    }
+
+    #[test]
+    fn test_duel_2() {
+        let (_, duel) = Duel::parse("B X").unwrap();
+        assert_eq!(duel.play(), 1);
+    }
+
+    #[test]
+    fn test_duel_3() {
+        let (_, duel) = Duel::parse("C Z").unwrap();
+        assert_eq!(duel.play(), 6);
+    }
+
+    #[test]
+    fn test_parse_unknown_strategy() {
+        assert_eq!(
+            parse_input_2("A Y\nB Q").err().unwrap().to_string(),
+            "day 2, line 2, column 3: unknown strategy, found `Q`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = r"A Y
+B X
+C Z"
+        .replace('\n', "\r\n")
+            + "\r\n";
+
+        assert_eq!(Day02.solve(Part::One, &input).unwrap(), Answer::Number(15));
+        assert_eq!(Day02.solve(Part::Two, &input).unwrap(), Answer::Number(12));
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), 15);
+        assert_eq!(part2(&parse_input_2(&input).unwrap()), 12);
+    }
+}
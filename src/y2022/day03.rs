@@ -0,0 +1,225 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{self, lines, take_while1};
+use crate::puzzle::{Answer, Puzzle};
+
+fn get_compartments(input: &str) -> (&str, &str) {
+    let split_at = input.len() / 2;
+    let first = &input[..split_at];
+    let last = &input[split_at..];
+    (first, last)
+}
+
+fn get_duplicate(input: (&str, &str)) -> Option<char> {
+    input.0.chars().find(|&c| input.1.contains(c))
+}
+
+fn get_priority(item: char) -> u32 {
+    if item.is_uppercase() {
+        item as u32 - 38
+    } else {
+        item as u32 - 96
+    }
+}
+
+fn calculate_priority(input: &str) -> u32 {
+    let compartments = get_compartments(input);
+    let duplicate = get_duplicate(compartments).expect("rucksacks are checked when parsing");
+    get_priority(duplicate)
+}
+
+fn calculate_priorities(input: Vec<&str>) -> u32 {
+    input.iter().map(|line| calculate_priority(line)).sum()
+}
+
+fn unique_item(input: Vec<&str>) -> Option<char> {
+    let [first, second, third] = input[..] else {
+        return None;
+    };
+
+    first
+        .chars()
+        .find(|&c| second.contains(c) && third.contains(c))
+}
+
+/// The sum of the badge priorities, or `None` if the rucksacks don't split into groups of three
+/// with an item in common.
+fn calculate_group_priorities(input: Vec<&str>) -> Option<u32> {
+    let mut group: Vec<&str> = Vec::new();
+    let mut result = 0;
+    for line in input {
+        group.push(line);
+
+        if group.len() == 3 {
+            let unique_item = unique_item(group)?;
+            result += get_priority(unique_item);
+            group = Vec::new();
+        }
+    }
+
+    group.is_empty().then_some(result)
+}
+
+/// The rucksacks, one line of items each. Every rucksack has an item in both of its compartments.
+fn parse_input(input: &str) -> Result<Vec<&str>, AocError> {
+    let rucksack = take_while1(|item| item.is_ascii_alphabetic(), "expected items");
+    let rucksacks = parse::run(3, lines(rucksack), input)?;
+
+    for &rucksack in &rucksacks {
+        let reason = if rucksack.len() % 2 != 0 {
+            "odd number of items"
+        } else if get_duplicate(get_compartments(rucksack)).is_none() {
+            "no item in both compartments"
+        } else {
+            continue;
+        };
+        return Err(AocError::at(3, input, rucksack, reason));
+    }
+
+    Ok(rucksacks)
+}
+
+#[aoc(day3, part1)]
+pub(crate) fn part1(input: &str) -> Result<u32, AocError> {
+    let input = &normalize(input);
+
+    Ok(calculate_priorities(parse_input(input)?))
+}
+
+#[aoc(day3, part2)]
+pub(crate) fn part2(input: &str) -> Result<Answer, AocError> {
+    let input = &normalize(input);
+
+    Ok(calculate_group_priorities(parse_input(input)?).into())
+}
+
+
+pub struct Day03;
+
+impl Puzzle for Day03 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_input(input)?.into_iter().map(str::to_owned).collect())
+    }
+
+    fn part1(&self, rucksacks: &Self::Input) -> Answer {
+        calculate_priorities(rucksacks.iter().map(String::as_str).collect()).into()
+    }
+
+    fn part2(&self, rucksacks: &Self::Input) -> Answer {
+        calculate_group_priorities(rucksacks.iter().map(String::as_str).collect()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r"vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn test_get_compartments() {
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp";
+        let expected = ("vJrwpWtwJgWr", "hcsFMMfFFhFp");
+
+        assert_eq!(get_compartments(input), expected);
+    }
+
+    #[test]
+    fn test_get_duplicate() {
+        let input = ("vJrwpWtwJgWr", "hcsFMMfFFhFp");
+        assert_eq!(get_duplicate(input), Some('p'));
+    }
+
+    #[test]
+    fn test_get_priority_lowercase() {
+        let alphabet: Vec<char> = "abcdefghijklmnopqrstuvwxyz".chars().collect();
+        let mut counter = 1;
+        alphabet.iter().for_each(|input| {
+            assert_eq!(get_priority(*input), counter);
+            counter += 1;
+        })
+    }
+
+    #[test]
+    fn test_get_priority_uppercase() {
+        let alphabet: Vec<char> = "ABCDEFGHIJKLMNOPQRSTUVWXYZ".chars().collect();
+        let mut counter = 27;
+        alphabet.iter().for_each(|input| {
+            assert_eq!(get_priority(*input), counter);
+            counter += 1;
+        })
+    }
+
+    #[test]
+    fn test_calculate_priority() {
+        let puzzle_input= TEST_INPUT.split("\n").collect();
+
+        assert_eq!(calculate_priorities(puzzle_input), 157)
+    }
+
+    #[test]
+    fn test_unique_item() {
+
+        let puzzle_input= TEST_INPUT.split("\n").take(3).collect();
+        assert_eq!(unique_item(puzzle_input), Some('r'))
+    }
+
+    #[test]
+    fn test_unique_item_2() {
+        let puzzle_input= TEST_INPUT.split("\n").take(3).collect();
+
+        assert_eq!(unique_item(puzzle_input), Some('r'))
+    }
+
+    #[test]
+    fn test_calculate_group_priorities() {
+        let puzzle_input= TEST_INPUT.split("\n").collect();
+
+        assert_eq!(calculate_group_priorities(puzzle_input), Some(70))
+    }
+
+    #[test]
+    fn parse_input_invalid_rucksacks() {
+        let error = |input| parse_input(input).unwrap_err().to_string();
+
+        assert_eq!(
+            error("abca\nbcdbc\nbd"),
+            "day 3, line 2, column 1: odd number of items, found `bcdbc`"
+        );
+        assert_eq!(
+            error("abca\nabcd\nbd"),
+            "day 3, line 2, column 1: no item in both compartments, found `abcd`"
+        );
+    }
+
+    #[test]
+    fn groups_without_badge() {
+        for input in ["abca\nbb\ncc", "aa\naa\naa\nbb"] {
+            assert!(Day03.solve(Part::One, input).is_ok());
+            assert_eq!(
+                Day03.solve(Part::Two, input).unwrap(),
+                Answer::from("no solution")
+            );
+            assert_eq!(part2(input).unwrap(), Answer::from("no solution"));
+        }
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(Day03.solve(Part::One, &input).unwrap(), Answer::Number(157));
+        assert_eq!(Day03.solve(Part::Two, &input).unwrap(), Answer::Number(70));
+
+        assert_eq!(part1(&input).unwrap(), 157);
+        assert_eq!(part2(&input).unwrap(), Answer::Number(70));
+    }
+}
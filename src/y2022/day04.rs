@@ -0,0 +1,275 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{self, lines, number, tag, PResult};
+use crate::puzzle::{Answer, Puzzle};
+
+/// An inclusive range of section IDs.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct Area {
+    pub(crate) from: i32,
+    pub(crate) to: i32,
+}
+
+impl Area {
+    pub(crate) fn new(from: i32, to: i32) -> Area {
+        Area { from, to }
+    }
+
+    /// The number of IDs in this area.
+    pub(crate) fn len(&self) -> u64 {
+        (self.to - self.from + 1) as u64
+    }
+
+    pub(crate) fn contains_id(&self, id: i32) -> bool {
+        self.from <= id && id <= self.to
+    }
+
+    /// Merges overlapping and adjacent areas, ordered by their first ID.
+    pub(crate) fn merge(mut areas: Vec<Area>) -> Vec<Area> {
+        areas.sort_unstable_by_key(|area| area.from);
+
+        let mut merged: Vec<Area> = Vec::with_capacity(areas.len());
+        for area in areas {
+            match merged.last_mut() {
+                Some(last) if area.from <= last.to.saturating_add(1) => {
+                    last.to = last.to.max(area.to)
+                }
+                _ => merged.push(area),
+            }
+        }
+
+        merged
+    }
+
+    fn parse(input: &str) -> PResult<'_, Area> {
+        let (input, from) = number(input)?;
+        let (input, _) = tag("-")(input)?;
+        let (input, to) = number(input)?;
+
+        Ok((input, Area { from, to }))
+    }
+
+    pub(crate) fn contains(&self, area: &Area) -> bool {
+        self.from <= area.from && self.to >= area.to
+    }
+
+    pub(crate) fn overlaps(&self, area: &Area) -> bool {
+        (self.to >= area.from && self.from <= area.from)
+            || (self.from <= area.to && self.from >= area.from)
+    }
+}
+
+fn pairs_where_one_range_includes_the_other(input: &[(Area, Area)]) -> u32 {
+    input
+        .iter()
+        .map(|area_tuple| {
+            if pair_contains_the_other(&area_tuple.0, &area_tuple.1) {
+                1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+fn get_areas(input: &str) -> PResult<'_, (Area, Area)> {
+    let (input, area_1) = Area::parse(input)?;
+    let (input, _) = tag(",")(input)?;
+    let (input, area_2) = Area::parse(input)?;
+
+    Ok((input, (area_1, area_2)))
+}
+
+fn pair_contains_the_other(area_1: &Area, area_2: &Area) -> bool {
+    area_1.contains(area_2) || area_2.contains(area_1)
+}
+
+fn pairs_overlap(area_1: &Area, area_2: &Area) -> bool {
+    area_1.overlaps(area_2)
+}
+
+fn pairs_where_ranges_overlap(input: &[(Area, Area)]) -> u32 {
+    input
+        .iter()
+        .map(|area_tuple| {
+            if pairs_overlap(&area_tuple.0, &area_tuple.1) {
+                // println!("{} overlaps {}", &area_tuple.0, &area_tuple.1);
+                1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+#[aoc_generator(day4)]
+pub(crate) fn parse_input(input: &str) -> Result<Vec<(Area, Area)>, AocError> {
+    let input = &normalize(input);
+
+    parse::run(4, lines(get_areas), input)
+}
+
+#[aoc(day4, part1)]
+pub(crate) fn part1(pairs: &[(Area, Area)]) -> u32 {
+    pairs_where_one_range_includes_the_other(pairs)
+}
+
+#[aoc(day4, part2)]
+pub(crate) fn part2(pairs: &[(Area, Area)]) -> u32 {
+    pairs_where_ranges_overlap(pairs)
+}
+
+
+pub struct Day04;
+
+impl Puzzle for Day04 {
+    type Input = Vec<(Area, Area)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> Answer {
+        part1(pairs).into()
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> Answer {
+        part2(pairs).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    #[test]
+    fn test_pairs_where_one_range_includes_the_other() {
+        let input = parse_input("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8").unwrap();
+
+        let expected: u32 = 2;
+
+        assert_eq!(pairs_where_one_range_includes_the_other(&input), expected);
+    }
+
+    #[test]
+    fn test_to_area() {
+        assert_eq!(Area::parse("2-4"), Ok(("", Area { from: 2, to: 4 })))
+    }
+
+    #[test]
+    fn test_to_areas() {
+        assert_eq!(
+            get_areas("2-4,6-8"),
+            Ok(("", (Area { from: 2, to: 4 }, Area { from: 6, to: 8 })))
+        )
+    }
+
+    #[test]
+    fn test_area_contains_negative() {
+        let area_1 = Area { from: 2, to: 4 };
+        let area_2 = Area { from: 6, to: 8 };
+
+        assert!(!area_1.contains(&area_2));
+        assert!(!area_2.contains(&area_1));
+    }
+
+    #[test]
+    fn test_area_contains_positive() {
+        let area_1 = Area { from: 2, to: 8 };
+        let area_2 = Area { from: 3, to: 7 };
+
+        assert!(area_1.contains(&area_2));
+        assert!(!area_2.contains(&area_1));
+    }
+
+    #[test]
+    fn test_area_overlaps_negative() {
+        let area_1 = Area { from: 2, to: 4 };
+        let area_2 = Area { from: 6, to: 8 };
+
+        assert!(!area_1.overlaps(&area_2));
+    }
+
+    #[test]
+    fn test_area_overlaps_positive() {
+        let area_1 = Area { from: 2, to: 8 };
+        let area_2 = Area { from: 3, to: 7 };
+
+        assert!(area_1.overlaps(&area_2));
+    }
+
+    #[test]
+    fn test_pair_contains_the_other() {
+        let area_1 = Area { from: 2, to: 4 };
+        let area_2 = Area { from: 6, to: 8 };
+
+        assert!(!pair_contains_the_other(&area_1, &area_2));
+    }
+
+    #[test]
+    fn test_pair_contains_the_other_positive() {
+        let area_1 = Area { from: 2, to: 8 };
+        let area_2 = Area { from: 3, to: 7 };
+
+        assert!(pair_contains_the_other(&area_1, &area_2));
+    }
+
+    #[test]
+    fn test_pairs_overlap() {
+        let area_1 = Area { from: 2, to: 4 };
+        let area_2 = Area { from: 6, to: 8 };
+
+        assert!(!pairs_overlap(&area_1, &area_2));
+    }
+
+    #[test]
+    fn test_pairs_overlap_positive() {
+        let area_1 = Area { from: 2, to: 8 };
+        let area_2 = Area { from: 3, to: 7 };
+
+        assert!(pairs_overlap(&area_1, &area_2));
+    }
+
+    #[test]
+    fn test_pairs_where_ranges_overlap() {
+        let input = parse_input("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8").unwrap();
+
+        assert_eq!(pairs_where_ranges_overlap(&input), 4);
+    }
+
+    #[test]
+    fn test_merge_areas() {
+        let merged = Area::merge(vec![
+            Area::new(12, 14),
+            Area::new(3, 5),
+            Area::new(6, 8),
+            Area::new(-2, 4),
+            Area::new(10, 10),
+        ]);
+
+        assert_eq!(
+            merged,
+            vec![Area::new(-2, 8), Area::new(10, 10), Area::new(12, 14)]
+        );
+        assert_eq!(merged.iter().map(Area::len).sum::<u64>(), 15);
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = r"2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8"
+            .replace('\n', "\r\n")
+            + "\r\n";
+
+        assert_eq!(Day04.solve(Part::One, &input).unwrap(), Answer::Number(2));
+        assert_eq!(Day04.solve(Part::Two, &input).unwrap(), Answer::Number(4));
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), 2);
+        assert_eq!(part2(&parse_input(&input).unwrap()), 4);
+    }
+}
@@ -0,0 +1,382 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{
+    self, all_consuming, alt, delimited, lines, many_to_end, map, map_res, number, preceded,
+    satisfy, separated_list1, space0, tag, PResult, ParseError,
+};
+use crate::puzzle::{Answer, Puzzle};
+
+type Stacks = Vec<Vec<char>>;
+
+#[derive(Debug)]
+pub struct Instruction {
+    from: usize,
+    to: usize,
+    size: u32,
+}
+
+#[derive(Debug)]
+struct InstructionError;
+
+impl Instruction {
+    /// A move between two of the `stacks` stacks.
+    fn parser(stacks: usize) -> impl Fn(&str) -> PResult<'_, Instruction> {
+        move |input| {
+            let (input, size) = preceded(tag("move "), number)(input)?;
+            let (input, from) = preceded(tag(" from "), stack_number(stacks))(input)?;
+            let (input, to) = preceded(tag(" to "), stack_number(stacks))(input)?;
+
+            Ok((input, Instruction { from, to, size }))
+        }
+    }
+}
+
+fn stack_number<'a>(stacks: usize) -> impl Fn(&'a str) -> PResult<'a, usize> {
+    map_res(number, move |stack| match stack {
+        0 => Err("stacks are numbered from 1"),
+        stack if stack > stacks => Err("unknown stack"),
+        stack => Ok(stack),
+    })
+}
+
+/// One row of the drawing: a crate like `[D]` or three spaces for every stack, separated by a
+/// space.
+fn crate_row(input: &str) -> PResult<'_, Vec<Option<char>>> {
+    let crate_name = satisfy(|name| name.is_ascii_alphabetic(), "expected a crate");
+    let slot = alt((
+        map(delimited(tag("["), crate_name, tag("]")), Some),
+        map(tag("   "), |_| None),
+    ));
+
+    separated_list1(tag(" "), slot)(input)
+}
+
+/// The drawing of the stacks, bottom row of crates first, followed by the numbers of the stacks.
+pub fn parse_stacks(input: &str) -> PResult<'_, Stacks> {
+    let (drawing, labels) = input.rsplit_once('\n').unwrap_or(("", input));
+    let (_, rows) = lines(crate_row)(drawing)?;
+    let (rest, labels) = many_to_end(delimited(space0, number::<usize>, space0))(labels)?;
+
+    let mut stacks: Stacks = vec![Vec::new(); labels.len()];
+    for (row, line) in rows.iter().zip(drawing.lines()) {
+        if row.len() > stacks.len() {
+            return Err(ParseError::new(line, "more crates than stacks"));
+        }
+    }
+
+    for row in rows.iter().rev() {
+        for (idx, slot) in row.iter().enumerate() {
+            if let Some(name) = slot {
+                stacks[idx].push(*name);
+            }
+        }
+    }
+
+    Ok((rest, stacks))
+}
+
+/// The rearrangement procedure for `stacks` stacks, one move per line.
+fn parse_moves(input: &str, stacks: usize) -> PResult<'_, Vec<Instruction>> {
+    lines(Instruction::parser(stacks))(input)
+}
+
+/// Checks that no move takes more crates than its stack holds. Both cranes move the same number
+/// of crates between the same stacks, so following the heights of the stacks is enough.
+fn check_moves<'a>(
+    stacks: &Stacks,
+    moves: &[Instruction],
+    lines: &'a str,
+) -> Result<(), ParseError<'a>> {
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+
+    for (instruction, line) in moves.iter().zip(lines.lines()) {
+        let size = instruction.size as usize;
+        if heights[instruction.from - 1] < size {
+            return Err(ParseError::new(line, "not enough crates to move"));
+        }
+        heights[instruction.from - 1] -= size;
+        heights[instruction.to - 1] += size;
+    }
+
+    Ok(())
+}
+
+/// The drawing of the stacks and the moves, separated by a blank line.
+fn stacks_and_moves(input: &str) -> PResult<'_, (Stacks, Vec<Instruction>)> {
+    let (drawing, moves) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::new(
+            &input[input.len()..],
+            "expected a blank line before the moves",
+        )
+    })?;
+    let (_, stacks) = all_consuming(parse_stacks)(drawing)?;
+    let (rest, instructions) = parse_moves(moves, stacks.len())?;
+    check_moves(&stacks, &instructions, moves)?;
+
+    Ok((rest, (stacks, instructions)))
+}
+
+fn parse_input(input: &str) -> Result<(Stacks, Vec<Instruction>), AocError> {
+    parse::run(5, stacks_and_moves, input)
+}
+
+fn execute_instruction(
+    stacks: &mut Stacks,
+    move_instruction: &Instruction,
+) -> Result<(), InstructionError> {
+    for _ in 0..move_instruction.size {
+        let val = stacks[move_instruction.from - 1]
+            .pop()
+            .ok_or(InstructionError)?;
+
+        stacks[move_instruction.to - 1].push(val);
+    }
+
+    Ok(())
+}
+
+fn execute_instruction_crane_9001(
+    stacks: &mut Stacks,
+    move_instruction: &Instruction,
+) -> Result<(), InstructionError> {
+    let from_idx = move_instruction.from - 1;
+    let stack_from_len = stacks[from_idx].len();
+    let start = stack_from_len
+        .checked_sub(move_instruction.size as usize)
+        .ok_or(InstructionError)?;
+    let elems = stacks[from_idx]
+        .drain(start..stack_from_len)
+        .collect::<Vec<_>>();
+    stacks[move_instruction.to - 1].extend(elems);
+    Ok(())
+}
+
+fn execute_moves(stacks: &mut Stacks, instructions: &[Instruction]) {
+    instructions
+        .iter()
+        .try_for_each(|instruction| execute_instruction(stacks, instruction))
+        .expect("moves are checked when parsing");
+}
+
+fn execute_moves_crane_9001(stacks: &mut Stacks, instructions: &[Instruction]) {
+    instructions
+        .iter()
+        .try_for_each(|instruction| execute_instruction_crane_9001(stacks, instruction))
+        .expect("moves are checked when parsing");
+}
+
+fn top_crates(stacks: &Stacks) -> String {
+    let mut top_crates = String::new();
+
+    for stack in stacks {
+        let top_char = stack.last();
+
+        if let Some(top_char) = top_char {
+            top_crates.push(*top_char);
+        }
+    }
+    top_crates
+}
+
+
+#[aoc(day5, part1)]
+pub(crate) fn part1(input: &str) -> Result<String, AocError> {
+    let input = &normalize(input);
+
+    let (mut stacks, moves) = parse_input(input)?;
+    execute_moves(&mut stacks, &moves);
+
+    Ok(top_crates(&stacks))
+}
+
+#[aoc(day5, part2)]
+pub(crate) fn part2(input: &str) -> Result<String, AocError> {
+    let input = &normalize(input);
+
+    let (mut stacks, moves) = parse_input(input)?;
+    execute_moves_crane_9001(&mut stacks, &moves);
+
+    Ok(top_crates(&stacks))
+}
+
+
+pub struct Day05;
+
+impl Puzzle for Day05 {
+    type Input = (Stacks, Vec<Instruction>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (stacks, moves): &Self::Input) -> Answer {
+        let mut stacks = stacks.clone();
+        execute_moves(&mut stacks, moves);
+
+        top_crates(&stacks).into()
+    }
+
+    fn part2(&self, (stacks, moves): &Self::Input) -> Answer {
+        let mut stacks = stacks.clone();
+        execute_moves_crane_9001(&mut stacks, moves);
+
+        top_crates(&stacks).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r#"    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 "#;
+
+    static TEST_INPUT_MOVES: &str = r#"move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2"#;
+
+    #[test]
+    fn test_parse_moves() {
+        let (_, moves) = parse_moves(TEST_INPUT_MOVES, 3).unwrap();
+
+        // Index values should be 1 less than text values
+        assert_eq!(moves[0].from, 2);
+        assert_eq!(moves[0].to, 1);
+        assert_eq!(moves[0].size, 1);
+
+        assert_eq!(moves[1].from, 1);
+        assert_eq!(moves[1].to, 3);
+        assert_eq!(moves[1].size, 3);
+        assert_eq!(moves.len(), 4);
+    }
+
+    #[test]
+    fn test_parse_stacks() {
+        let (_, stacks) = parse_stacks(TEST_INPUT).unwrap();
+
+        assert_eq!(stacks[0][0], 'Z');
+        assert_eq!(stacks[1][2], 'D');
+        assert_eq!(stacks[2][0], 'P');
+
+        assert_eq!(stacks.len(), 3);
+    }
+
+    #[test]
+    fn test_execute_moves() {
+        let (_, mut stacks) = parse_stacks(TEST_INPUT).unwrap();
+        let (_, moves) = parse_moves(TEST_INPUT_MOVES, 3).unwrap();
+
+        execute_instruction(&mut stacks, &moves[0])
+            .map_err(|err| println!("{:?}", err))
+            .unwrap();
+
+        assert_eq!(stacks[0][2], 'D');
+
+        execute_instruction(&mut stacks, &moves[1])
+            .map_err(|err| println!("{:?}", err))
+            .unwrap();
+
+        assert_eq!(stacks[2][3], 'Z');
+
+        execute_instruction(&mut stacks, &moves[2])
+            .map_err(|err| println!("{:?}", err))
+            .unwrap();
+
+        assert_eq!(stacks.get(1), Some(&vec![]));
+    }
+
+    #[test]
+    fn part_1() {
+        let (_, mut stacks) = parse_stacks(TEST_INPUT).unwrap();
+        let (_, moves) = parse_moves(TEST_INPUT_MOVES, 3).unwrap();
+        execute_moves(&mut stacks, &moves);
+
+        assert_eq!(stacks[1][0], 'M');
+
+        let top_crates = top_crates(&stacks);
+
+        assert_eq!(top_crates, "CMZ".to_string());
+    }
+
+    #[test]
+    fn part_2() {
+        let (_, mut stacks) = parse_stacks(TEST_INPUT).unwrap();
+        let (_, moves) = parse_moves(TEST_INPUT_MOVES, 3).unwrap();
+        execute_moves_crane_9001(&mut stacks, &moves);
+
+        // assert_eq!(stacks[1][0], 'M');
+
+        let top_crates = top_crates(&stacks);
+
+        assert_eq!(top_crates, "MCD".to_string());
+    }
+
+    #[test]
+    fn test_parse_moves_invalid() {
+        let input = format!("{TEST_INPUT}\n\nmove 1 from 2 to 1\nmove 3 from 1 to");
+
+        assert_eq!(
+            parse_input(&input).err().unwrap().to_string(),
+            "day 5, line 7, column 17: expected ` to `, found end of line"
+        );
+        assert_eq!(
+            parse_input(&input.replace("from 2", "from 0"))
+                .err()
+                .unwrap()
+                .to_string(),
+            "day 5, line 6, column 13: stacks are numbered from 1, found `0`"
+        );
+    }
+
+    #[test]
+    fn test_impossible_moves() {
+        let input = format!("{TEST_INPUT}\n\n{TEST_INPUT_MOVES}");
+
+        assert_eq!(
+            parse_input(&input.replace("from 2 to 1", "from 9 to 1"))
+                .err()
+                .unwrap()
+                .to_string(),
+            "day 5, line 6, column 13: unknown stack, found `9`"
+        );
+        assert_eq!(
+            parse_input(&input.replace("move 3 from 1", "move 5 from 1"))
+                .err()
+                .unwrap()
+                .to_string(),
+            "day 5, line 7, column 1: not enough crates to move, found `move 5 from 1 to 3`"
+        );
+        assert_eq!(
+            parse_input(&input.replace("[Z] [M] [P]", "[Z] [M] [P] [Q]"))
+                .err()
+                .unwrap()
+                .to_string(),
+            "day 5, line 3, column 1: more crates than stacks, found `[Z] [M] [P] [Q]`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = r"    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2"
+            .replace('\n', "\r\n")
+            + "\r\n";
+
+        assert_eq!(Day05.solve(Part::One, &input).unwrap(), Answer::from("CMZ"));
+        assert_eq!(Day05.solve(Part::Two, &input).unwrap(), Answer::from("MCD"));
+
+        assert_eq!(part1(&input).unwrap(), "CMZ");
+        assert_eq!(part2(&input).unwrap(), "MCD");
+    }
+}
@@ -0,0 +1,150 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{self, take_while1};
+use crate::puzzle::{Answer, Puzzle};
+use std::collections::HashSet;
+
+fn no_duplicates(input: &str) -> bool {
+    let char_vector: Vec<char> = input.chars().collect();
+    let unique_characters: HashSet<char> = HashSet::from_iter(char_vector);
+    let number_of_duplicates = input.len() - unique_characters.len();
+    number_of_duplicates == 0
+}
+
+/// The number of characters up to and including the first `marker_size` distinct ones, or `None`
+/// if there are no such characters.
+fn first_marker_at(input: &str, marker_size: usize) -> Option<usize> {
+    for index in 0..=input.len() {
+        if index >= marker_size {
+            let marker_candidate = &input[index - marker_size..index];
+            if no_duplicates(marker_candidate) {
+                return Some(index);
+            }
+        }
+    }
+    None
+}
+
+fn parse_input(input: &str) -> Result<&str, AocError> {
+    let datastream = take_while1(|c| c.is_ascii_lowercase(), "expected a datastream");
+
+    parse::run(6, datastream, input)
+}
+
+#[aoc(day6, part1)]
+pub(crate) fn part1(input: &str) -> Result<Answer, AocError> {
+    let input = &normalize(input);
+
+    Ok(first_marker_at(parse_input(input)?, 4).into())
+}
+
+#[aoc(day6, part2)]
+pub(crate) fn part2(input: &str) -> Result<Answer, AocError> {
+    let input = &normalize(input);
+
+    Ok(first_marker_at(parse_input(input)?, 14).into())
+}
+
+
+pub struct Day06;
+
+impl Puzzle for Day06 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_input(input)?.to_owned())
+    }
+
+    fn part1(&self, datastream: &Self::Input) -> Answer {
+        first_marker_at(datastream, 4).into()
+    }
+
+    fn part2(&self, datastream: &Self::Input) -> Answer {
+        first_marker_at(datastream, 14).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    #[test]
+    fn test_example_1() {
+        let input = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        assert_eq!(first_marker_at(input, 4), Some(5));
+    }
+
+    #[test]
+    fn test_example_2() {
+        let input = "nppdvjthqldpwncqszvftbrmjlhg";
+        assert_eq!(first_marker_at(input, 4), Some(6));
+    }
+
+    #[test]
+    fn test_example_3() {
+        let input = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+        assert_eq!(first_marker_at(input, 4), Some(10));
+    }
+
+    #[test]
+    fn test_example_4() {
+        let input = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+        assert_eq!(first_marker_at(input, 4), Some(11));
+    }
+
+    // part 2
+    #[test]
+    fn test_example_2_1() {
+        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        assert_eq!(first_marker_at(input, 14), Some(19));
+    }
+
+    #[test]
+    fn test_example_2_2() {
+        let input = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        assert_eq!(first_marker_at(input, 14), Some(23));
+    }
+
+    #[test]
+    fn test_example_2_3() {
+        let input = "nppdvjthqldpwncqszvftbrmjlhg";
+        assert_eq!(first_marker_at(input, 14), Some(23));
+    }
+
+    #[test]
+    fn test_example_2_4() {
+        let input = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+        assert_eq!(first_marker_at(input, 14), Some(29));
+    }
+
+    #[test]
+    fn test_example_2_5() {
+        let input = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+        assert_eq!(first_marker_at(input, 14), Some(26));
+    }
+
+    #[test]
+    fn no_marker() {
+        assert_eq!(first_marker_at("abcd", 4), Some(4));
+        assert_eq!(first_marker_at("abcd", 14), None);
+
+        for input in ["x", "aaaa"] {
+            assert_eq!(
+                Day06.solve(Part::One, input).unwrap(),
+                Answer::from("no solution")
+            );
+        }
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb".replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(Day06.solve(Part::One, &input).unwrap(), Answer::Number(7));
+        assert_eq!(Day06.solve(Part::Two, &input).unwrap(), Answer::Number(19));
+
+        assert_eq!(part1(&input).unwrap(), Answer::Number(7));
+        assert_eq!(part2(&input).unwrap(), Answer::Number(19));
+    }
+}
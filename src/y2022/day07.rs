@@ -0,0 +1,251 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{
+    self, alt, context, lines, map, number, pair, preceded, space1, tag, token, PResult, ParseError,
+};
+use crate::puzzle::{Answer, Puzzle};
+use std::{
+    collections::HashMap,
+};
+
+#[derive(Debug)]
+pub enum Node {
+    Directory(Vec<String>, HashMap<String, Node>),
+    File(usize),
+}
+
+impl Node {
+    fn size(&self) -> usize {
+        match self {
+            Node::Directory(_, memory) => memory.values().map(|n| n.size()).sum(),
+            Node::File(size) => *size,
+        }
+    }
+
+    fn total_at_most(&self, max: usize) -> usize {
+        match self {
+            Node::Directory(_, memory) => {
+                let size = self.size();
+                (if size <= max { size } else { 0 })
+                    + memory.values().map(|n| n.total_at_most(max)).sum::<usize>()
+            }
+            Node::File(_) => 0,
+        }
+    }
+
+    fn smallest_directory_of_at_least(&self, space_to_delete: usize) -> Option<usize> {
+        if self.size() < space_to_delete {
+            None
+        } else {
+            match self {
+                Node::Directory(_, memory) => memory
+                    .values()
+                    .filter_map(|f| f.smallest_directory_of_at_least(space_to_delete))
+                    .chain(std::iter::once(self.size()))
+                    .min(),
+                Node::File(_) => None,
+            }
+        }
+    }
+}
+
+/// A line of the terminal output, borrowing the names from the input.
+enum TerminalLine<'a> {
+    ChangeDirectory(&'a str),
+    List,
+    Directory,
+    File(usize, &'a str),
+}
+
+impl<'a> TerminalLine<'a> {
+    fn parse(input: &'a str) -> PResult<'a, TerminalLine<'a>> {
+        alt((TerminalLine::command, TerminalLine::listing))(input)
+    }
+
+    fn command(input: &'a str) -> PResult<'a, TerminalLine<'a>> {
+        let (rest, command) = preceded(tag("$ "), token)(input)?;
+
+        match command {
+            "cd" => map(preceded(space1, token), TerminalLine::ChangeDirectory)(rest),
+            "ls" => Ok((rest, TerminalLine::List)),
+            _ => Err(ParseError::new(command, "unsupported command")),
+        }
+    }
+
+    fn listing(input: &'a str) -> PResult<'a, TerminalLine<'a>> {
+        alt((
+            map(preceded(tag("dir "), token), |_| TerminalLine::Directory),
+            map(
+                pair(
+                    context(number, "expected a file size"),
+                    preceded(space1, token),
+                ),
+                |(size, name)| TerminalLine::File(size, name),
+            ),
+        ))(input)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Node, AocError> {
+    let terminal_output = parse::run(7, lines(TerminalLine::parse), input)?;
+    let mut root = Node::Directory(vec!["/".into()], Default::default());
+
+    let mut current_directory: &mut Node = &mut root;
+    for line in terminal_output {
+        match line {
+            TerminalLine::ChangeDirectory("/") => {
+                current_directory = &mut root;
+            }
+            TerminalLine::ChangeDirectory("..") => {
+                if let Node::Directory(path, _members) = current_directory {
+                    let parent_path: Vec<String> =
+                        path.iter().rev().skip(1).rev().cloned().collect();
+                    for loc in parent_path {
+                        if loc == "/" {
+                            current_directory = &mut root;
+                        } else {
+                            if let Node::Directory(_, members) = current_directory {
+                                current_directory = members.get_mut(&loc).unwrap();
+                            }
+                        }
+                    }
+                }
+            }
+            TerminalLine::ChangeDirectory(location) => {
+                if let Node::Directory(path, members) = current_directory {
+                    let new_path = path
+                        .clone()
+                        .into_iter()
+                        .chain(std::iter::once(location.to_owned()))
+                        .collect();
+                    current_directory = members
+                        .entry(location.to_owned())
+                        .or_insert(Node::Directory(new_path, Default::default()));
+                }
+            }
+            TerminalLine::List | TerminalLine::Directory => continue,
+            TerminalLine::File(file_size, name) => {
+                if let Node::Directory(_, members) = current_directory {
+                    members.insert(name.to_owned(), Node::File(file_size));
+                }
+            }
+        }
+    }
+    Ok(root)
+}
+
+/// The size of the smallest directory to delete for the update, or `None` if there is already
+/// enough free space.
+fn space_to_free_up(root: &Node) -> Option<usize> {
+    let total_space = 70000000;
+    let required_space = 30000000;
+    let cur_space = root.size();
+    let space_to_delete = cur_space.checked_sub(total_space - required_space)?;
+
+    root.smallest_directory_of_at_least(space_to_delete)
+}
+
+#[aoc(day7, part1)]
+pub(crate) fn part1(input: &str) -> Result<usize, AocError> {
+    let input = &normalize(input);
+
+    let root = parse_input(input)?;
+
+    Ok(root.total_at_most(100000))
+}
+
+#[aoc(day7, part2)]
+pub(crate) fn part2(input: &str) -> Result<Answer, AocError> {
+    let input = &normalize(input);
+
+    let root = parse_input(input)?;
+
+    Ok(space_to_free_up(&root).into())
+}
+
+pub struct Day07;
+
+impl Puzzle for Day07 {
+    type Input = Node;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, root: &Self::Input) -> Answer {
+        root.total_at_most(100000).into()
+    }
+
+    fn part2(&self, root: &Self::Input) -> Answer {
+        space_to_free_up(root).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    #[test]
+    fn enough_free_space() {
+        for input in ["$ cd /", "$ cd /\n$ ls\n1000 a"] {
+            assert_eq!(
+                Day07.solve(Part::Two, input).unwrap(),
+                Answer::from("no solution")
+            );
+        }
+    }
+
+    #[test]
+    fn parse_input_unsupported_command() {
+        assert_eq!(
+            parse_input("$ cd /\n$ rm a\n").unwrap_err().to_string(),
+            "day 7, line 2, column 3: unsupported command, found `rm`"
+        );
+        assert_eq!(
+            parse_input("$ cd /\n$ ls\n12k a").unwrap_err().to_string(),
+            "day 7, line 3, column 1: expected a file size, found `12k`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = r"$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k"
+            .replace('\n', "\r\n")
+            + "\r\n";
+
+        assert_eq!(
+            Day07.solve(Part::One, &input).unwrap(),
+            Answer::Number(95437)
+        );
+        assert_eq!(
+            Day07.solve(Part::Two, &input).unwrap(),
+            Answer::Number(24933642)
+        );
+
+        assert_eq!(part1(&input).unwrap(), 95437);
+        assert_eq!(part2(&input).unwrap(), Answer::Number(24933642));
+    }
+}
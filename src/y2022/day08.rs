@@ -1,45 +1,44 @@
+use crate::error::AocError;
+use crate::geometry::{Direction, Point};
+use crate::input::normalize;
+use crate::parse::{self, lines, many_to_end, map, satisfy, PResult};
+use crate::puzzle::{Answer, Puzzle};
 use grid::*;
 
-fn visible_top(grid: &Grid<u32>, row: usize, col: usize) -> bool {
+/// Heights of the trees between the tree at `row`/`col` and the edge in `direction`, nearest
+/// first.
+fn line_of_sight(
+    grid: &Grid<u32>,
+    row: usize,
+    col: usize,
+    direction: Direction,
+) -> impl Iterator<Item = u32> + '_ {
+    let step = direction.offset();
+    std::iter::successors(Some(Point::from_grid_index(row, col) + step), move |tree| {
+        Some(*tree + step)
+    })
+    .map_while(|tree| tree.get(grid).copied())
+}
+
+fn visible_from(grid: &Grid<u32>, row: usize, col: usize, direction: Direction) -> bool {
     let tree_size = grid.get(row, col).unwrap();
+    line_of_sight(grid, row, col, direction).all(|ts| ts < *tree_size)
+}
 
-    for r in 0..row {
-        if grid.get(r, col).unwrap().ge(tree_size) {
-            return false;
-        }
-    }
-    true
+fn visible_top(grid: &Grid<u32>, row: usize, col: usize) -> bool {
+    visible_from(grid, row, col, Direction::Up)
 }
 
 fn visible_bottom(grid: &Grid<u32>, row: usize, col: usize) -> bool {
-    let tree_size = grid.get(row, col).unwrap();
-    for r in row + 1..grid.rows() {
-        if grid.get(r, col).unwrap().ge(tree_size) {
-            return false;
-        }
-    }
-    true
+    visible_from(grid, row, col, Direction::Down)
 }
 
 fn visible_left(grid: &Grid<u32>, row: usize, col: usize) -> bool {
-    let tree_size = grid.get(row, col).unwrap();
-    for c in 0..col {
-        if grid.get(row, c).unwrap().ge(tree_size) {
-            return false;
-        }
-    }
-    true
+    visible_from(grid, row, col, Direction::Left)
 }
 
 fn visible_right(grid: &Grid<u32>, row: usize, col: usize) -> bool {
-    let tree_size = grid.get(row, col).unwrap();
-    for c in col + 1..grid.cols() {
-        if grid.get(row, c).unwrap().ge(tree_size) {
-            return false;
-        }
-    }
-
-    true
+    visible_from(grid, row, col, Direction::Right)
 }
 
 fn is_visible(grid: &Grid<u32>, row: usize, col: usize) -> bool {
@@ -55,16 +54,24 @@ fn is_visible(grid: &Grid<u32>, row: usize, col: usize) -> bool {
      top | bottom | left | right
 }
 
-fn create_grid(input: &str) -> Grid<u32> {
-    let lines: Vec<&str> = input.split("\n").collect();
-    let col_length = lines.get(0).unwrap().len();
+fn tree_row(input: &str) -> PResult<'_, Vec<u32>> {
+    let height = satisfy(|tree| tree.is_ascii_digit(), "expected a tree height");
+
+    many_to_end(map(height, |tree| tree.to_digit(10).unwrap()))(input)
+}
+
+fn create_grid(input: &str) -> Result<Grid<u32>, AocError> {
+    let rows = parse::run(8, lines(tree_row), input)?;
+    let col_length = rows.first().map_or(0, Vec::len);
 
-    let data = input.lines()
-        .map(|line| line.chars())
-        .flatten()
-        .map(|t| t.to_digit(10).unwrap())
-        .collect::<Vec<u32>>();
-    Grid::from_vec(data, col_length)
+    let mut data = Vec::with_capacity(input.len());
+    for (row, line) in rows.into_iter().zip(input.lines()) {
+        if row.len() != col_length {
+            return Err(AocError::at(8, input, line, "rows must have equal width"));
+        }
+        data.extend(row);
+    }
+    Ok(Grid::from_vec(data, col_length))
 }
 
 fn count_visible(grid: &Grid<u32>) -> u32 {
@@ -82,75 +89,34 @@ fn count_visible(grid: &Grid<u32>) -> u32 {
 }
 
 
-fn visible_trees_top(grid: &Grid<u32>, row: usize, col: usize) -> u32 {
+fn visible_trees(grid: &Grid<u32>, row: usize, col: usize, direction: Direction) -> u32 {
     let mut result: u32 = 0;
     let tree_size = grid.get(row, col).unwrap();
-    for r in (0..row).rev() {
-        match grid.get(r, col) {
-            None => {}
-            Some(ts) => {
-                result += 1;
-                if ts >= tree_size {
-                    break;
-                }
-            }
+    for ts in line_of_sight(grid, row, col, direction) {
+        result += 1;
+        if ts >= *tree_size {
+            break;
         }
     }
-    return result;
+    result
+}
+
+fn visible_trees_top(grid: &Grid<u32>, row: usize, col: usize) -> u32 {
+    visible_trees(grid, row, col, Direction::Up)
 }
 
 fn visible_trees_bottom(grid: &Grid<u32>, row: usize, col: usize) -> u32 {
-    let mut result: u32 = 0;
-    let tree_size = grid.get(row, col).unwrap();
-    for r in row + 1..grid.rows() {
-        match grid.get(r, col) {
-            None => {}
-            Some(ts) => {
-                result += 1;
-                if ts >= tree_size {
-                    break;
-                }
-            }
-        }
-    }
-    return result;
+    visible_trees(grid, row, col, Direction::Down)
 }
 
 fn visible_trees_left(grid: &Grid<u32>, row: usize, col: usize) -> u32 {
-    let mut result: u32 = 0;
-    let tree_size = grid.get(row, col).unwrap();
-    for c in (0..col).rev() {
-        match grid.get(row, c) {
-            None => {}
-            Some(ts) => {
-                result += 1;
-                if ts >= tree_size {
-                    break;
-                }
-            }
-        }
-    }
-    return result;
+    visible_trees(grid, row, col, Direction::Left)
 }
 
 fn visible_trees_right(grid: &Grid<u32>, row: usize, col: usize) -> u32 {
-    let mut result: u32 = 0;
-    let tree_size = grid.get(row, col).unwrap();
-    for c in (col + 1)..grid.cols() {
-        match grid.get(row, c) {
-            None => {}
-            Some(ts) => {
-                result += 1;
-                if ts >= tree_size {
-                    break;
-                }
-            }
-        }
-    }
-    return result;
+    visible_trees(grid, row, col, Direction::Right)
 }
 
-
 fn scenic_score(grid: &Grid<u32>, row: usize, col: usize) -> u32 {
     visible_trees_top(grid, row, col) * visible_trees_left(grid, row, col) * visible_trees_bottom(grid, row, col) * visible_trees_right(grid, row, col)
 }
@@ -171,21 +137,44 @@ fn highest_scenic_score(grid: &Grid<u32>) -> u32 {
 
 
 #[aoc(day8, part1)]
-fn part1(input: &str) -> u32 {
-    let grid = create_grid(input);
-    count_visible(&grid)
+pub(crate) fn part1(input: &str) -> Result<u32, AocError> {
+    let input = &normalize(input);
+
+    let grid = create_grid(input)?;
+    Ok(count_visible(&grid))
 }
 
 #[aoc(day8, part2)]
-fn part2(input: &str) -> u32 {
-    let grid = create_grid(input);
-    highest_scenic_score(&grid)
+pub(crate) fn part2(input: &str) -> Result<u32, AocError> {
+    let input = &normalize(input);
+
+    let grid = create_grid(input)?;
+    Ok(highest_scenic_score(&grid))
 }
 
 
+pub struct Day08;
+
+impl Puzzle for Day08 {
+    type Input = Grid<u32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        create_grid(input)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Answer {
+        count_visible(grid).into()
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Answer {
+        highest_scenic_score(grid).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::puzzle::{Part, Solver};
 
     #[test]
     fn test_parse() {
@@ -206,7 +195,15 @@ mod test {
 
         assert_eq!(5, expected.cols());
         assert_eq!(5, expected.rows());
-        assert_eq!(create_grid(input), expected);
+        assert_eq!(create_grid(input).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parse_invalid_height() {
+        assert_eq!(
+            create_grid("303\n2x5\n653").unwrap_err().to_string(),
+            "day 8, line 2, column 2: expected a tree height, found `x`"
+        );
     }
 
     #[test]
@@ -219,7 +216,7 @@ mod test {
             5,
         );
 
-        assert_eq!(is_visible(&grid, 0, 0), true)
+        assert!(is_visible(&grid, 0, 0))
     }
 
     #[test]
@@ -232,7 +229,7 @@ mod test {
             5,
         );
 
-        assert_eq!(is_visible(&grid, 1, 1), true)
+        assert!(is_visible(&grid, 1, 1))
     }
 
     #[test]
@@ -245,7 +242,7 @@ mod test {
             5,
         );
 
-        assert_eq!(is_visible(&grid, 1, 3), false)
+        assert!(!is_visible(&grid, 1, 3))
     }
 
     #[test]
@@ -258,7 +255,7 @@ mod test {
             5,
         );
 
-        assert_eq!(is_visible(&grid, 3, 3), false)
+        assert!(!is_visible(&grid, 3, 3))
     }
 
     #[test]
@@ -271,10 +268,10 @@ mod test {
             5,
         );
 
-        assert_eq!(visible_top(&grid, 3, 1), false);
-        assert_eq!(visible_bottom(&grid, 3, 1), false);
-        assert_eq!(visible_left(&grid, 3, 1), false);
-        assert_eq!(visible_right(&grid, 3, 1), false);
+        assert!(!visible_top(&grid, 3, 1));
+        assert!(!visible_bottom(&grid, 3, 1));
+        assert!(!visible_left(&grid, 3, 1));
+        assert!(!visible_right(&grid, 3, 1));
     }
 
     #[test]
@@ -360,4 +357,21 @@ mod test {
         assert_eq!(visible_trees_right(&grid, 3, 2), 2);
         assert_eq!(scenic_score(&grid, 3, 2), 8);
     }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = r"30373
+25512
+65332
+33549
+35390"
+            .replace('\n', "\r\n")
+            + "\r\n";
+
+        assert_eq!(Day08.solve(Part::One, &input).unwrap(), Answer::Number(21));
+        assert_eq!(Day08.solve(Part::Two, &input).unwrap(), Answer::Number(8));
+
+        assert_eq!(part1(&input).unwrap(), 21);
+        assert_eq!(part2(&input).unwrap(), 8);
+    }
 }
@@ -0,0 +1,179 @@
+use crate::error::AocError;
+use crate::geometry::{Direction, Point};
+use crate::input::normalize;
+use crate::parse::{
+    self, context, lines, map, map_res, number, separated_pair, space1, token, PResult,
+};
+use crate::puzzle::{Answer, Puzzle};
+use std::{
+    collections::HashSet,
+};
+
+pub struct Motion {
+    direction: Direction,
+    steps: usize,
+}
+
+struct Rope {
+    tail_positions: Vec<Point>,
+}
+
+impl Rope {
+    fn tail(&self) -> Point {
+        *self.tail_positions.last().unwrap()
+    }
+
+    fn pull_tail(&mut self) {
+        let mut previous_position = *self.tail_positions.first().unwrap();
+
+        for tail_position in self.tail_positions.iter_mut().skip(1) {
+            if previous_position.chebyshev_distance(*tail_position) > 1 {
+                *tail_position += (previous_position - *tail_position).signum();
+            }
+
+            previous_position = *tail_position;
+        }
+    }
+
+    fn step(&mut self, direction: Direction) {
+        let head = self.tail_positions.first_mut().unwrap();
+        *head += direction.offset();
+
+        self.pull_tail();
+    }
+}
+
+impl Motion {
+    fn parse(input: &str) -> PResult<'_, Motion> {
+        let direction = map_res(token, |direction| match direction {
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            "U" => Ok(Direction::Up),
+            "D" => Ok(Direction::Down),
+            _ => Err("unknown direction"),
+        });
+        let steps = context(number, "expected a number of steps");
+
+        map(
+            separated_pair(direction, space1, steps),
+            |(direction, steps)| Motion { direction, steps },
+        )(input)
+    }
+}
+
+#[aoc_generator(day9)]
+pub(crate) fn input_generator(input: &str) -> Result<Vec<Motion>, AocError> {
+    let input = &normalize(input);
+
+    parse::run(9, lines(Motion::parse), input)
+}
+
+#[aoc(day9, part1)]
+pub(crate) fn part1(series_of_motions: &[Motion]) -> usize {
+    let mut rope = Rope {
+        tail_positions: vec![Point::ORIGIN; 2],
+    };
+
+    let mut tail_visited = HashSet::with_capacity(series_of_motions.len());
+    tail_visited.insert(Point::ORIGIN);
+
+    for motion in series_of_motions {
+        for _ in 0..motion.steps {
+            rope.step(motion.direction);
+            tail_visited.insert(rope.tail());
+        }
+    }
+
+    tail_visited.len()
+}
+
+#[aoc(day9, part2)]
+pub(crate) fn part2(series_of_motions: &[Motion]) -> usize {
+    let mut rope = Rope {
+        tail_positions: vec![Point::ORIGIN; 10],
+    };
+
+    let mut tail_visited = HashSet::with_capacity(series_of_motions.len());
+    tail_visited.insert(Point::ORIGIN);
+
+    for motion in series_of_motions {
+        for _ in 0..motion.steps {
+            rope.step(motion.direction);
+            tail_visited.insert(rope.tail());
+        }
+    }
+
+    tail_visited.len()
+}
+
+pub struct Day09;
+
+impl Puzzle for Day09 {
+    type Input = Vec<Motion>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        input_generator(input)
+    }
+
+    fn part1(&self, series_of_motions: &Self::Input) -> Answer {
+        part1(series_of_motions).into()
+    }
+
+    fn part2(&self, series_of_motions: &Self::Input) -> Answer {
+        part2(series_of_motions).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT_1: &str = r"R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+    static TEST_INPUT_2: &str = r"R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&input_generator(TEST_INPUT_1).unwrap()), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&input_generator(TEST_INPUT_1).unwrap()), 1);
+        assert_eq!(part2(&input_generator(TEST_INPUT_2).unwrap()), 36);
+    }
+
+    #[test]
+    fn input_generator_unknown_direction() {
+        assert_eq!(
+            input_generator("R 4\nX 4").err().unwrap().to_string(),
+            "day 9, line 2, column 1: unknown direction, found `X`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT_1.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(Day09.solve(Part::One, &input).unwrap(), Answer::Number(13));
+        assert_eq!(Day09.solve(Part::Two, &input).unwrap(), Answer::Number(1));
+
+        assert_eq!(part1(&input_generator(&input).unwrap()), 13);
+        assert_eq!(part2(&input_generator(&input).unwrap()), 1);
+    }
+}
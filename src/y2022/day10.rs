@@ -1,8 +1,12 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{self, lines, map, number, preceded, space1, token, PResult, ParseError};
+use crate::puzzle::{Answer, Puzzle};
 use std::fmt::{Display, Formatter};
 
-enum Instruction {
-    ADD(i32),
-    NOOP,
+pub enum Instruction {
+    Add(i32),
+    Noop,
 }
 
 const SIGNAL_STRENGTH_MEASURE_CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
@@ -13,23 +17,23 @@ const CRT_HEIGHT: usize = 6;
 impl Instruction {
     fn duration(&self) -> usize {
         match self {
-            Instruction::ADD(_) => 2,
-            Instruction::NOOP => 1,
+            Instruction::Add(_) => 2,
+            Instruction::Noop => 1,
         }
     }
 }
 
 #[derive(Clone, Copy)]
 enum PixelState {
-    LIGHT,
-    DARK,
+    Light,
+    Dark,
 }
 
 impl Display for PixelState {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            PixelState::LIGHT => write!(f, "#"),
-            PixelState::DARK => write!(f, "."),
+            PixelState::Light => write!(f, "#"),
+            PixelState::Dark => write!(f, "."),
         }
     }
 }
@@ -53,24 +57,27 @@ impl Display for Crt {
 }
 
 
+impl Instruction {
+    fn parse(input: &str) -> PResult<'_, Instruction> {
+        let (rest, name) = token(input)?;
+
+        match name {
+            "addx" => map(preceded(space1, number), Instruction::Add)(rest),
+            "noop" => Ok((rest, Instruction::Noop)),
+            _ => Err(ParseError::new(name, "unknown instruction")),
+        }
+    }
+}
+
 #[aoc_generator(day10)]
-fn parse(input: &str) -> Vec<Instruction> {
-    input
-        .lines()
-        .map(|instruction| {
-            let mut tokens = instruction.split_ascii_whitespace();
-
-            match tokens.next().unwrap() {
-                "addx" => Instruction::ADD(tokens.next().unwrap().parse().unwrap()),
-                "noop" => Instruction::NOOP,
-                _ => unreachable!(),
-            }
-        })
-        .collect()
+pub(crate) fn parse(input: &str) -> Result<Vec<Instruction>, AocError> {
+    let input = &normalize(input);
+
+    parse::run(10, lines(Instruction::parse), input)
 }
 
 #[aoc(day10, part1)]
-fn part1(instructions: &[Instruction]) -> i32 {
+pub(crate) fn part1(instructions: &[Instruction]) -> i32 {
     let mut cycles_passed = 0;
     let mut x_value = 1;
     let mut signal_strengths_sum = 0;
@@ -89,7 +96,7 @@ fn part1(instructions: &[Instruction]) -> i32 {
 
         cycles_passed += instruction.duration();
 
-        if let Instruction::ADD(value) = instruction {
+        if let Instruction::Add(value) = instruction {
             x_value += value;
         }
     });
@@ -98,10 +105,10 @@ fn part1(instructions: &[Instruction]) -> i32 {
 }
 
 #[aoc(day10, part2)]
-fn part2(program: &[Instruction]) -> String {
+pub(crate) fn part2(program: &[Instruction]) -> String {
     let mut cycles_passed = 0;
     let mut x_value = 1;
-    let mut crt = Crt([PixelState::DARK; CRT_WIDTH * CRT_HEIGHT]);
+    let mut crt = Crt([PixelState::Dark; CRT_WIDTH * CRT_HEIGHT]);
 
     for instruction in program {
         let sprite_pixels_horizontal_positions = [x_value - 1, x_value, x_value + 1];
@@ -113,13 +120,13 @@ fn part2(program: &[Instruction]) -> String {
 
         for pixel_position in drawn_pixels {
             if sprite_pixels_horizontal_positions.contains(&((pixel_position % CRT_WIDTH) as i32)) {
-                crt.0[pixel_position] = PixelState::LIGHT;
+                crt.0[pixel_position] = PixelState::Light;
             }
         }
 
         cycles_passed += instruction.duration();
 
-        if let Instruction::ADD(value) = instruction {
+        if let Instruction::Add(value) = instruction {
             x_value += value;
         }
     }
@@ -128,9 +135,28 @@ fn part2(program: &[Instruction]) -> String {
 }
 
 
+pub struct Day10;
+
+impl Puzzle for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(&self, program: &Self::Input) -> Answer {
+        part1(program).into()
+    }
+
+    fn part2(&self, program: &Self::Input) -> Answer {
+        part2(program).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::puzzle::{Part, Solver};
 
     static TEST_INPUT: &str = r"addx 15
 addx -11
@@ -282,13 +308,13 @@ noop
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(&parse(TEST_INPUT)), 13140);
+        assert_eq!(part1(&parse(TEST_INPUT).unwrap()), 13140);
     }
 
     #[test]
     fn part2_example() {
         assert_eq!(
-            part2(&parse(TEST_INPUT)),
+            part2(&parse(TEST_INPUT).unwrap()),
             r"##..##..##..##..##..##..##..##..##..##..
 ###...###...###...###...###...###...###.
 ####....####....####....####....####....
@@ -298,4 +324,32 @@ noop
                 .to_string()
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn parse_unknown_instruction() {
+        assert_eq!(
+            parse("noop\naddx 3\nsubx 2").err().unwrap().to_string(),
+            "day 10, line 3, column 1: unknown instruction, found `subx`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(
+            Day10.solve(Part::One, &input).unwrap(),
+            Answer::Number(13140)
+        );
+        assert_eq!(
+            Day10.solve(Part::Two, &input).unwrap(),
+            Day10.solve(Part::Two, TEST_INPUT).unwrap()
+        );
+
+        assert_eq!(part1(&parse(&input).unwrap()), 13140);
+        assert_eq!(
+            part2(&parse(&input).unwrap()),
+            part2(&parse(TEST_INPUT).unwrap())
+        );
+    }
+}
@@ -0,0 +1,617 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{
+    self, alt, delimited, lines, map, map_res, number, pair, preceded, separated_list1,
+    separated_pair, space0, space1, tag, take_while1, token, PResult, ParseError,
+};
+use crate::puzzle::{Answer, Puzzle};
+use std::collections::VecDeque;
+use std::fmt;
+
+#[derive(Clone, Debug)]
+enum Operand {
+    Number(u64),
+    Old,
+}
+
+impl Operand {
+    fn parse(input: &str) -> PResult<'_, Operand> {
+        alt((
+            map(tag("old"), |_| Operand::Old),
+            map(number, Operand::Number),
+        ))(input)
+    }
+}
+
+/// An arithmetic operator of a monkey's operation or job. Day 11 only has `+` and `*`, day 21
+/// has all four.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operator {
+    pub(crate) fn parse(input: &str) -> PResult<'_, Operator> {
+        map_res(token, |operator| match operator {
+            "+" => Ok(Operator::Add),
+            "-" => Ok(Operator::Subtract),
+            "*" => Ok(Operator::Multiply),
+            "/" => Ok(Operator::Divide),
+            _ => Err("unknown operator"),
+        })(input)
+    }
+
+    /// `lhs <operator> rhs`, or `None` if it overflows or divides by zero.
+    pub(crate) fn apply(self, lhs: i64, rhs: i64) -> Option<i64> {
+        match self {
+            Operator::Add => lhs.checked_add(rhs),
+            Operator::Subtract => lhs.checked_sub(rhs),
+            Operator::Multiply => lhs.checked_mul(rhs),
+            Operator::Divide => lhs.checked_div(rhs),
+        }
+    }
+
+    /// The `lhs` for which `lhs <operator> rhs` is `result`, or `None` if there is no such
+    /// integer.
+    pub(crate) fn solve_lhs(self, result: i64, rhs: i64) -> Option<i64> {
+        match self {
+            Operator::Add => result.checked_sub(rhs),
+            Operator::Subtract => result.checked_add(rhs),
+            Operator::Multiply => exact_div(result, rhs),
+            Operator::Divide => result.checked_mul(rhs).filter(|_| rhs != 0),
+        }
+    }
+
+    /// The `rhs` for which `lhs <operator> rhs` is `result`, or `None` if there is no such
+    /// integer.
+    pub(crate) fn solve_rhs(self, result: i64, lhs: i64) -> Option<i64> {
+        match self {
+            Operator::Add => result.checked_sub(lhs),
+            Operator::Subtract => lhs.checked_sub(result),
+            Operator::Multiply => exact_div(result, lhs),
+            Operator::Divide => exact_div(lhs, result),
+        }
+    }
+}
+
+/// `dividend / divisor` if it leaves no remainder.
+fn exact_div(dividend: i64, divisor: i64) -> Option<i64> {
+    dividend
+        .checked_rem(divisor)
+        .filter(|&remainder| remainder == 0)
+        .and_then(|_| dividend.checked_div(divisor))
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Operator::Add => "+",
+            Operator::Subtract => "-",
+            Operator::Multiply => "*",
+            Operator::Divide => "/",
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+/// An operator of a worry level operation, which only adds or multiplies.
+fn worry_operator(input: &str) -> PResult<'_, Operator> {
+    let (rest, operator) = Operator::parse(input)?;
+
+    match operator {
+        Operator::Add | Operator::Multiply => Ok((rest, operator)),
+        Operator::Subtract | Operator::Divide => Err(ParseError::new(
+            &input[..input.len() - rest.len()],
+            "unknown operator",
+        )),
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Operation {
+    operand_left: Operand,
+    operator: Operator,
+    operand_right: Operand,
+}
+
+impl Operation {
+    /// An expression like `old * 19`.
+    fn parse(input: &str) -> PResult<'_, Operation> {
+        let (input, operand_left) = Operand::parse(input)?;
+        let (input, operator) = preceded(space1, worry_operator)(input)?;
+        let (input, operand_right) = preceded(space1, Operand::parse)(input)?;
+
+        Ok((
+            input,
+            Operation {
+                operand_left,
+                operator,
+                operand_right,
+            },
+        ))
+    }
+
+    fn execute(&self, old: u64) -> u64 {
+        let lhs = match self.operand_left {
+            Operand::Number(operand) => operand,
+            Operand::Old => old,
+        };
+
+        let rhs = match self.operand_right {
+            Operand::Number(operand) => operand,
+            Operand::Old => old,
+        };
+
+        match self.operator {
+            Operator::Add => lhs + rhs,
+            Operator::Multiply => lhs * rhs,
+            Operator::Subtract | Operator::Divide => unreachable!("rejected when parsing"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Test {
+    divisible_by: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    starting_items: VecDeque<u64>,
+    operation: Operation,
+    test: Test,
+}
+
+/// A line of the notes. Fields belong to the monkey of the closest header above them.
+enum Note<'a> {
+    /// The span of the `Monkey N:` header and `N`.
+    Header(&'a str, usize),
+    /// The span of the field name and the field.
+    Field(&'a str, Field<'a>),
+    Blank,
+}
+
+enum Field<'a> {
+    StartingItems(VecDeque<u64>),
+    Operation(Operation),
+    DivisibleBy(u64),
+    /// The span of the monkey thrown to and its number.
+    IfTrue(&'a str, usize),
+    IfFalse(&'a str, usize),
+}
+
+impl<'a> Note<'a> {
+    /// Parses a line of the notes, ignoring indentation and the spacing around the separators.
+    fn parse(input: &'a str) -> PResult<'a, Note<'a>> {
+        let (input, _) = space0(input)?;
+        if input.is_empty() {
+            return Ok((input, Note::Blank));
+        }
+
+        alt((Note::header, Note::field))(input)
+    }
+
+    fn header(input: &'a str) -> PResult<'a, Note<'a>> {
+        let (rest, monkey) =
+            delimited(pair(tag("Monkey"), space1), number, pair(space0, tag(":")))(input)?;
+
+        Ok((
+            rest,
+            Note::Header(&input[..input.len() - rest.len()], monkey),
+        ))
+    }
+
+    fn field(input: &'a str) -> PResult<'a, Note<'a>> {
+        let (rest, name) = take_while1(|c| c != ':', "expected a field")(input)?;
+        let name = name.trim_end();
+        let (rest, _) = pair(tag(":"), space0)(rest)?;
+
+        let (rest, field) = match name {
+            "Starting items" if rest.is_empty() => (rest, Field::StartingItems(VecDeque::new())),
+            "Starting items" => map(
+                separated_list1(delimited(space0, tag(","), space0), number),
+                |items| Field::StartingItems(items.into()),
+            )(rest)?,
+            "Operation" => map(
+                preceded(
+                    separated_pair(tag("new"), space0, pair(tag("="), space0)),
+                    Operation::parse,
+                ),
+                Field::Operation,
+            )(rest)?,
+            "Test" => map(
+                preceded(pair(tag("divisible by"), space1), number),
+                Field::DivisibleBy,
+            )(rest)?,
+            "If true" => map(throw_to, |(span, monkey)| Field::IfTrue(span, monkey))(rest)?,
+            "If false" => map(throw_to, |(span, monkey)| Field::IfFalse(span, monkey))(rest)?,
+            _ => return Err(ParseError::new(name, "unknown field")),
+        };
+
+        Ok((rest, Note::Field(name, field)))
+    }
+}
+
+/// A `throw to monkey N` target, with the span of `N`.
+fn throw_to(input: &str) -> PResult<'_, (&str, usize)> {
+    let (input, _) = pair(tag("throw to monkey"), space1)(input)?;
+    let (rest, monkey) = number(input)?;
+
+    Ok((rest, (&input[..input.len() - rest.len()], monkey)))
+}
+
+fn monkey_business(monkeys: &[Monkey], worry_relief: bool, number_of_rounds: usize) -> usize {
+    let mut inspected_items: Vec<usize> = vec![0; monkeys.len()];
+    let mut monkeys = monkeys.to_vec();
+
+    let common_multiple: u64 = monkeys
+        .iter()
+        .map(|monkey| monkey.test.divisible_by)
+        .product();
+
+    for _round in 0..number_of_rounds {
+        for id in 0..monkeys.len() {
+            let monkey = &mut monkeys[id];
+            inspected_items[id] += monkey.starting_items.len();
+
+            let if_true_id = monkey.test.if_true;
+            let mut if_true_items = VecDeque::with_capacity(monkey.starting_items.len());
+
+            let if_false_id = monkey.test.if_false;
+            let mut if_false_items = VecDeque::with_capacity(monkey.starting_items.len());
+
+            while !monkey.starting_items.is_empty() {
+                let mut worry_level = monkey.starting_items.pop_front().unwrap();
+                worry_level = monkey.operation.execute(worry_level);
+
+                if worry_relief {
+                    worry_level /= 3;
+                } else {
+                    worry_level %= common_multiple;
+                }
+
+                if worry_level.is_multiple_of(monkey.test.divisible_by) {
+                    if_true_items.push_back(worry_level);
+                } else {
+                    if_false_items.push_back(worry_level);
+                }
+            }
+
+            monkeys[if_true_id]
+                .starting_items
+                .append(&mut if_true_items);
+            monkeys[if_false_id]
+                .starting_items
+                .append(&mut if_false_items);
+        }
+    }
+
+    inspected_items.sort_unstable();
+    inspected_items.iter().rev().take(2).product()
+}
+
+/// The fields of a monkey collected so far, in whatever order they appear.
+#[derive(Default)]
+struct MonkeyFields {
+    starting_items: Option<VecDeque<u64>>,
+    operation: Option<Operation>,
+    divisible_by: Option<u64>,
+    if_true: Option<usize>,
+    if_false: Option<usize>,
+}
+
+impl MonkeyFields {
+    fn set<'a>(&mut self, name: &'a str, field: Field<'a>) -> Result<(), ParseError<'a>> {
+        fn once<T>(slot: &mut Option<T>, value: T) -> bool {
+            slot.replace(value).is_none()
+        }
+
+        let first = match field {
+            Field::StartingItems(items) => once(&mut self.starting_items, items),
+            Field::Operation(operation) => once(&mut self.operation, operation),
+            Field::DivisibleBy(divisor) => once(&mut self.divisible_by, divisor),
+            Field::IfTrue(_, monkey) => once(&mut self.if_true, monkey),
+            Field::IfFalse(_, monkey) => once(&mut self.if_false, monkey),
+        };
+
+        if first {
+            Ok(())
+        } else {
+            Err(ParseError::new(name, "duplicate field"))
+        }
+    }
+
+    /// `header` is the span blamed for missing fields.
+    fn build(self, header: &str) -> Result<Monkey, ParseError<'_>> {
+        let missing = |name| ParseError::new(header, format!("missing `{name}`"));
+
+        Ok(Monkey {
+            starting_items: self
+                .starting_items
+                .ok_or_else(|| missing("Starting items"))?,
+            operation: self.operation.ok_or_else(|| missing("Operation"))?,
+            test: Test {
+                divisible_by: self.divisible_by.ok_or_else(|| missing("Test"))?,
+                if_true: self.if_true.ok_or_else(|| missing("If true"))?,
+                if_false: self.if_false.ok_or_else(|| missing("If false"))?,
+            },
+        })
+    }
+}
+
+/// Parses the notes into monkeys indexed by their `Monkey N:` numbers, which must run from 0
+/// without gaps, with every monkey throwing to another one.
+fn monkeys(input: &str) -> PResult<'_, Vec<Monkey>> {
+    let (rest, notes) = lines(Note::parse)(input)?;
+    let mut parsed = Vec::new();
+    // the spans of the monkeys thrown to, with the number of the monkey throwing
+    let mut targets = Vec::new();
+    let mut current: Option<(&str, usize, MonkeyFields)> = None;
+
+    for note in notes {
+        match note {
+            Note::Header(header, monkey) => {
+                let next = (header, monkey, MonkeyFields::default());
+                if let Some((previous, monkey, fields)) = current.replace(next) {
+                    parsed.push((previous, monkey, fields.build(previous)?));
+                }
+            }
+            Note::Field(name, field) => match &mut current {
+                Some((_, monkey, fields)) => {
+                    if let Field::IfTrue(span, target) | Field::IfFalse(span, target) = field {
+                        targets.push((span, *monkey, target));
+                    }
+                    fields.set(name, field)?;
+                }
+                None => return Err(ParseError::new(name, "expected `Monkey N:` first")),
+            },
+            Note::Blank => {}
+        }
+    }
+    if let Some((header, monkey, fields)) = current {
+        parsed.push((header, monkey, fields.build(header)?));
+    }
+
+    let count = parsed.len();
+    let mut monkeys: Vec<Option<Monkey>> = vec![None; count];
+    for (header, number, monkey) in parsed {
+        match monkeys.get_mut(number) {
+            Some(slot @ None) => *slot = Some(monkey),
+            Some(Some(_)) => return Err(ParseError::new(header, "duplicate monkey")),
+            None => {
+                let reason = format!("monkeys must be numbered 0 to {}", count - 1);
+                return Err(ParseError::new(header, reason));
+            }
+        }
+    }
+    for (span, monkey, target) in targets {
+        if target >= count {
+            return Err(ParseError::new(span, "unknown monkey"));
+        }
+        if target == monkey {
+            return Err(ParseError::new(span, "monkey throws to itself"));
+        }
+    }
+
+    // `count` monkeys numbered below `count` without duplicates leave no gaps
+    Ok((rest, monkeys.into_iter().flatten().collect()))
+}
+
+#[aoc_generator(day11)]
+pub(crate) fn parse_input(input: &str) -> Result<Vec<Monkey>, AocError> {
+    let input = &normalize(input);
+
+    parse::run(11, monkeys, input)
+}
+
+#[aoc(day11, part1)]
+pub(crate) fn part1(monkeys: &[Monkey]) -> usize {
+    monkey_business(monkeys, true, 20)
+}
+
+#[aoc(day11, part2)]
+pub(crate) fn part2(monkeys: &[Monkey]) -> usize {
+    monkey_business(monkeys, false, 10000)
+}
+
+pub struct Day11;
+
+impl Puzzle for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, monkeys: &Self::Input) -> Answer {
+        part1(monkeys).into()
+    }
+
+    fn part2(&self, monkeys: &Self::Input) -> Answer {
+        part2(monkeys).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r"Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 10605);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), 2713310158);
+    }
+
+    #[test]
+    fn parse_input_invalid_operator() {
+        let input = TEST_INPUT.replace("old + 6", "old - 6");
+
+        assert_eq!(
+            parse_input(&input).unwrap_err().to_string(),
+            "day 11, line 10, column 24: unknown operator, found `-`"
+        );
+    }
+
+    #[test]
+    fn parse_input_division() {
+        let input = TEST_INPUT.replace("old * 19", "old / 19");
+
+        assert_eq!(
+            parse_input(&input).unwrap_err().to_string(),
+            "day 11, line 3, column 24: unknown operator, found `/`"
+        );
+    }
+
+    #[test]
+    fn operator_inverse() {
+        for operator in [
+            Operator::Add,
+            Operator::Subtract,
+            Operator::Multiply,
+            Operator::Divide,
+        ] {
+            let result = operator.apply(84, 4).unwrap();
+
+            assert_eq!(operator.solve_lhs(result, 4), Some(84), "{operator}");
+            assert_eq!(operator.solve_rhs(result, 84), Some(4), "{operator}");
+        }
+        assert_eq!(Operator::Multiply.solve_lhs(7, 2), None);
+        assert_eq!(Operator::Divide.solve_rhs(7, 20), None);
+        assert_eq!(Operator::Divide.solve_lhs(7, 0), None);
+    }
+
+    #[test]
+    fn parse_input_reordered_fields() {
+        let input = "Monkey 0:
+Test: divisible by 23
+        If false: throw to monkey 1
+   Operation:  new=old * 19
+  If true:throw to monkey 1
+ Starting items: 79,98
+Monkey 1:
+  Starting items:
+  Operation: new = old + 3
+  Test: divisible by 17
+  If true: throw to monkey 0
+  If false: throw to monkey 0";
+
+        let monkeys = parse_input(input).unwrap();
+
+        assert_eq!(monkeys.len(), 2);
+        assert_eq!(monkeys[0].starting_items, VecDeque::from([79, 98]));
+        assert_eq!(monkeys[0].operation.execute(2), 38);
+        assert_eq!(monkeys[0].test.divisible_by, 23);
+        assert_eq!((monkeys[0].test.if_true, monkeys[0].test.if_false), (1, 1));
+        assert!(monkeys[1].starting_items.is_empty());
+    }
+
+    #[test]
+    fn parse_input_missing_and_duplicate_fields() {
+        let missing = TEST_INPUT.replacen("  Test: divisible by 23\n", "", 1);
+        let duplicate = TEST_INPUT.replacen("Monkey 1:", "  Test: divisible by 7\nMonkey 1:", 1);
+
+        assert_eq!(
+            parse_input(&missing).unwrap_err().to_string(),
+            "day 11, line 1, column 1: missing `Test`, found `Monkey 0:`"
+        );
+        assert_eq!(
+            parse_input(&duplicate).unwrap_err().to_string(),
+            "day 11, line 8, column 3: duplicate field, found `Test`"
+        );
+    }
+
+    #[test]
+    fn parse_input_monkey_numbers() {
+        let mut blocks: Vec<&str> = TEST_INPUT.split("\n\n").collect();
+        blocks.reverse();
+        let monkeys = parse_input(&blocks.join("\n\n")).unwrap();
+
+        assert_eq!(monkeys[0].starting_items, VecDeque::from([79, 98]));
+        assert_eq!(monkeys[3].starting_items, VecDeque::from([74]));
+        assert_eq!(part1(&monkeys), 10605);
+
+        let single = "Monkey 1:
+  Starting items: 79
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 1
+    If false: throw to monkey 1";
+        assert_eq!(
+            parse_input(single).unwrap_err().to_string(),
+            "day 11, line 1, column 1: monkeys must be numbered 0 to 0, found `Monkey 1:`"
+        );
+        assert_eq!(
+            parse_input(&single.replace("onkey 1", "onkey 0"))
+                .unwrap_err()
+                .to_string(),
+            "day 11, line 5, column 30: monkey throws to itself, found `0`"
+        );
+        assert_eq!(
+            parse_input(&TEST_INPUT.replacen("throw to monkey 2", "throw to monkey 7", 1))
+                .unwrap_err()
+                .to_string(),
+            "day 11, line 5, column 30: unknown monkey, found `7`"
+        );
+        assert_eq!(
+            parse_input(&TEST_INPUT.replacen("Monkey 1:", "Monkey 0:", 1))
+                .unwrap_err()
+                .to_string(),
+            "day 11, line 8, column 1: duplicate monkey, found `Monkey 0:`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(
+            Day11.solve(Part::One, &input).unwrap(),
+            Answer::Number(10605)
+        );
+        assert_eq!(
+            Day11.solve(Part::Two, &input).unwrap(),
+            Answer::Number(2713310158)
+        );
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), 10605);
+        assert_eq!(part2(&parse_input(&input).unwrap()), 2713310158);
+    }
+}
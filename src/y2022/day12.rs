@@ -0,0 +1,223 @@
+use crate::error::AocError;
+use crate::geometry::Point;
+use crate::input::normalize;
+use crate::parse::{self, lines, many_to_end, map_res, satisfy, PResult};
+use crate::puzzle::{Answer, Puzzle};
+use crate::search::bfs;
+use grid::*;
+
+const LOWEST_ELEVATION: u32 = 'a' as u32;
+const HIGHEST_ELEVATION: u32 = 'z' as u32;
+
+pub struct Map {
+    elevations: Grid<u32>,
+    current_position: Point,
+    best_signal_location: Point,
+}
+
+impl Map {
+    fn neighbor_elevations(&self, current_position: Point) -> Vec<(Point, u32)> {
+        current_position
+            .neighbors4()
+            .filter_map(|neighbor| {
+                let elevation = neighbor.get(&self.elevations)?.to_owned();
+                Some((neighbor, elevation))
+            })
+            .collect()
+    }
+}
+
+/// A square of the heightmap, with the start and the end kept apart from their elevations.
+#[derive(Clone, Copy)]
+enum Square {
+    Start,
+    End,
+    Elevation(u32),
+}
+
+impl Square {
+    fn parse(input: &str) -> PResult<'_, Square> {
+        map_res(
+            satisfy(|_| true, "expected a square"),
+            |square| match square {
+                'S' => Ok(Square::Start),
+                'E' => Ok(Square::End),
+                'a'..='z' => Ok(Square::Elevation(square as u32)),
+                _ => Err("unknown elevation"),
+            },
+        )(input)
+    }
+
+    fn elevation(self) -> u32 {
+        match self {
+            Square::Start => LOWEST_ELEVATION,
+            Square::End => HIGHEST_ELEVATION,
+            Square::Elevation(elevation) => elevation,
+        }
+    }
+}
+
+#[aoc_generator(day12)]
+pub(crate) fn parse_input(input: &str) -> Result<Map, AocError> {
+    let input = &normalize(input);
+
+    let rows = parse::run(12, lines(many_to_end(Square::parse)), input)?;
+    let mut current_position = None;
+    let mut best_signal_location = None;
+    let mut elevations = Grid::new(0, 0);
+
+    for (y, (row, line)) in rows.into_iter().zip(input.lines()).enumerate() {
+        if row.is_empty() {
+            return Err(AocError::at(12, input, line, "empty row"));
+        }
+        if y > 0 && row.len() != elevations.cols() {
+            return Err(AocError::at(12, input, line, "rows must have equal width"));
+        }
+
+        for (x, square) in row.iter().enumerate() {
+            match square {
+                Square::Start => current_position = Some(Point::from_grid_index(y, x)),
+                Square::End => best_signal_location = Some(Point::from_grid_index(y, x)),
+                Square::Elevation(_) => {}
+            }
+        }
+        elevations.push_row(row.into_iter().map(Square::elevation).collect());
+    }
+
+    let end_of_input = &input[input.trim_end().len()..];
+    let missing = |marker| AocError::at(12, input, end_of_input, marker);
+
+    Ok(Map {
+        elevations,
+        current_position: current_position.ok_or_else(|| missing("missing start `S`"))?,
+        best_signal_location: best_signal_location.ok_or_else(|| missing("missing end `E`"))?,
+    })
+}
+
+/// The fewest steps from `start` to a square that `is_goal` accepts, where `climbable` tells
+/// whether a step may go from the first elevation to the second.
+fn fewest_steps(
+    map: &Map,
+    start: Point,
+    climbable: impl Fn(u32, u32) -> bool,
+    is_goal: impl Fn(Point, u32) -> bool,
+) -> Option<usize> {
+    let elevation = *start.get(&map.elevations)?;
+
+    bfs(
+        (start, elevation),
+        |&(position, elevation)| {
+            map.neighbor_elevations(position)
+                .into_iter()
+                .filter(|&(_, neighbor_elevation)| climbable(elevation, neighbor_elevation))
+                .collect::<Vec<_>>()
+        },
+        |&(position, _)| position,
+        |&(position, elevation)| is_goal(position, elevation),
+    )
+    .map(|(_, steps)| steps)
+}
+
+#[aoc(day12, part1)]
+pub(crate) fn part1(map: &Map) -> Option<usize> {
+    fewest_steps(
+        map,
+        map.current_position,
+        |from, to| to as i32 - from as i32 <= 1,
+        |position, _| position == map.best_signal_location,
+    )
+}
+
+/// The same search backwards, from the end down to any square of the lowest elevation.
+#[aoc(day12, part2)]
+pub(crate) fn part2(map: &Map) -> Option<usize> {
+    fewest_steps(
+        map,
+        map.best_signal_location,
+        |from, to| from as i32 - to as i32 <= 1,
+        |_, elevation| elevation == LOWEST_ELEVATION,
+    )
+}
+
+pub struct Day12;
+
+impl Puzzle for Day12 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Answer {
+        part1(map).into()
+    }
+
+    fn part2(&self, map: &Self::Input) -> Answer {
+        part2(map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r"Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), Some(31));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), Some(29));
+    }
+
+    #[test]
+    fn unreachable_end() {
+        let input = "Sbz\nazE";
+
+        for part in [Part::One, Part::Two] {
+            assert_eq!(
+                Day12.solve(part, input).unwrap(),
+                Answer::Text("no solution".to_owned())
+            );
+        }
+    }
+
+    #[test]
+    fn parse_input_empty_row() {
+        assert_eq!(
+            parse_input("\nSE").err().unwrap().to_string(),
+            "day 12, line 1, column 1: empty row, found end of line"
+        );
+        assert_eq!(
+            parse_input("Sa\n\nbE").err().unwrap().to_string(),
+            "day 12, line 2, column 1: empty row, found end of line"
+        );
+    }
+
+    #[test]
+    fn parse_input_unknown_elevation() {
+        assert_eq!(
+            parse_input("Sab\nac?\nEzz").err().unwrap().to_string(),
+            "day 12, line 2, column 3: unknown elevation, found `?`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(Day12.solve(Part::One, &input).unwrap(), Answer::Number(31));
+        assert_eq!(Day12.solve(Part::Two, &input).unwrap(), Answer::Number(29));
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), Some(31));
+        assert_eq!(part2(&parse_input(&input).unwrap()), Some(29));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{
+    self, alt, blocks, delimited, map, number, opt, separated_list1, separated_pair, tag, PResult,
+};
+use crate::puzzle::{Answer, Puzzle};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A packet of the distress signal, either an integer or a list of packets.
+#[derive(Clone, Debug)]
+pub enum Packet {
+    Integer(u32),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    fn parse(input: &str) -> PResult<'_, Packet> {
+        alt((
+            map(number, Packet::Integer),
+            map(
+                delimited(
+                    tag("["),
+                    opt(separated_list1(tag(","), Packet::parse)),
+                    tag("]"),
+                ),
+                |packets| Packet::List(packets.unwrap_or_default()),
+            ),
+        ))(input)
+    }
+
+    /// A divider packet `[[value]]`.
+    fn divider(value: u32) -> Packet {
+        Packet::List(vec![Packet::List(vec![Packet::Integer(value)])])
+    }
+}
+
+/// Integers compare by value and lists element by element, with the shorter list first
+/// if one runs out. An integer compared to a list is treated as a list of just that integer.
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Integer(left), Packet::Integer(right)) => left.cmp(right),
+            (Packet::List(left), Packet::List(right)) => left.cmp(right),
+            (Packet::Integer(left), Packet::List(right)) => {
+                [Packet::Integer(*left)].as_slice().cmp(right.as_slice())
+            }
+            (Packet::List(left), Packet::Integer(right)) => {
+                left.as_slice().cmp([Packet::Integer(*right)].as_slice())
+            }
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Equal in the puzzle's order, so `1` equals `[1]` and `[[1]]`.
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Integer(value) => write!(f, "{value}"),
+            Packet::List(packets) => {
+                write!(f, "[")?;
+                for (index, packet) in packets.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{packet}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+#[aoc_generator(day13)]
+pub(crate) fn parse_input(input: &str) -> Result<Vec<(Packet, Packet)>, AocError> {
+    let input = &normalize(input);
+
+    let pair = separated_pair(Packet::parse, tag("\n"), Packet::parse);
+
+    parse::run(13, blocks(pair), input)
+}
+
+#[aoc(day13, part1)]
+pub(crate) fn part1(pairs: &[(Packet, Packet)]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, (left, right))| left < right)
+        .map(|(index, _)| index + 1)
+        .sum()
+}
+
+#[aoc(day13, part2)]
+pub(crate) fn part2(pairs: &[(Packet, Packet)]) -> usize {
+    let first_divider = Packet::divider(2);
+    let second_divider = Packet::divider(6);
+    let packets = pairs.iter().flat_map(|(left, right)| [left, right]);
+
+    // the dividers' indices after sorting only depend on how many packets sort before them
+    let (mut first_index, mut second_index) = (1, 2);
+    for packet in packets {
+        if *packet < first_divider {
+            first_index += 1;
+        }
+        if *packet < second_divider {
+            second_index += 1;
+        }
+    }
+
+    first_index * second_index
+}
+
+pub struct Day13;
+
+impl Puzzle for Day13 {
+    type Input = Vec<(Packet, Packet)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> Answer {
+        part1(pairs).into()
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> Answer {
+        part2(pairs).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r"[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), 140);
+    }
+
+    #[test]
+    fn packet_order() {
+        let pairs = parse_input(TEST_INPUT).unwrap();
+        let ordered: Vec<bool> = pairs.iter().map(|(left, right)| left < right).collect();
+
+        assert_eq!(
+            ordered,
+            vec![true, true, false, true, false, true, false, false]
+        );
+        assert_eq!(pairs[7].0.to_string(), "[1,[2,[3,[4,[5,6,7]]]],8,9]");
+        assert_eq!(Packet::Integer(1), Packet::divider(1));
+    }
+
+    #[test]
+    fn parse_input_unclosed_list() {
+        assert_eq!(
+            parse_input("[1,[2]\n[1]").err().unwrap().to_string(),
+            "day 13, line 1, column 7: expected `]`, found end of line"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(Day13.solve(Part::One, &input).unwrap(), Answer::Number(13));
+        assert_eq!(Day13.solve(Part::Two, &input).unwrap(), Answer::Number(140));
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), 13);
+        assert_eq!(part2(&parse_input(&input).unwrap()), 140);
+    }
+}
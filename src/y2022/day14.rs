@@ -0,0 +1,309 @@
+use crate::error::AocError;
+use crate::geometry::Point;
+use crate::input::normalize;
+use crate::parse::{
+    self, lines, map, map_res, number, separated_list1, separated_pair, tag, PResult,
+};
+use crate::puzzle::{Answer, Puzzle};
+use grid::*;
+use std::fmt;
+
+const SAND_SOURCE: Point = Point::new(500, 0);
+
+/// Where a grain of sand tries to go next, in order of preference.
+const FALL_OFFSETS: [Point; 3] = [Point::new(0, 1), Point::new(-1, 1), Point::new(1, 1)];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Tile {
+    #[default]
+    Air,
+    Rock,
+    Sand,
+}
+
+/// The scanned slice of the cave. The grid only spans the columns sand can ever reach, which is
+/// at most as far from the source as the floor is deep.
+#[derive(Clone)]
+pub struct Cave {
+    tiles: Grid<Tile>,
+    left: i32,
+    lowest_rock: i32,
+    floor: bool,
+}
+
+impl Cave {
+    fn new(paths: &[Vec<Point>]) -> Cave {
+        let lowest_rock = paths
+            .iter()
+            .flatten()
+            .map(|point| point.y)
+            .max()
+            .unwrap_or(0);
+        let floor = lowest_rock + 2;
+        let left = SAND_SOURCE.x - floor;
+        let right = SAND_SOURCE.x + floor;
+
+        let mut cave = Cave {
+            tiles: Grid::new(floor as usize, (right - left + 1) as usize),
+            left,
+            lowest_rock,
+            floor: false,
+        };
+
+        for path in paths {
+            for segment in path.windows(2) {
+                let step = (segment[1] - segment[0]).signum();
+                let mut rock = segment[0];
+                cave.set(rock, Tile::Rock);
+                while rock != segment[1] {
+                    rock += step;
+                    cave.set(rock, Tile::Rock);
+                }
+            }
+        }
+
+        cave
+    }
+
+    fn index(&self, point: Point) -> Option<(usize, usize)> {
+        (point - Point::new(self.left, 0)).to_grid_index(&self.tiles)
+    }
+
+    fn get(&self, point: Point) -> Tile {
+        self.index(point)
+            .and_then(|(row, col)| self.tiles.get(row, col).copied())
+            .unwrap_or_default()
+    }
+
+    /// Sets a tile, ignoring rocks out of the reach of any sand.
+    fn set(&mut self, point: Point, tile: Tile) {
+        if let Some((row, col)) = self.index(point) {
+            *self.tiles.get_mut(row, col).unwrap() = tile;
+        }
+    }
+
+    fn is_free(&self, point: Point) -> bool {
+        point.y < self.lowest_rock + 2 && self.get(point) == Tile::Air
+    }
+
+    /// Pours sand until it either falls below the lowest rock into the void or, with a floor,
+    /// blocks the source, and returns the number of grains that came to rest.
+    ///
+    /// Every grain follows the path of the previous one up to where that one came to rest, so
+    /// the path is kept as a stack and each grain starts from the last position before it.
+    fn pour(&mut self) -> usize {
+        let mut path = vec![SAND_SOURCE];
+        let mut grains = 0;
+
+        while let Some(&grain) = path.last() {
+            if !self.floor && grain.y > self.lowest_rock {
+                break;
+            }
+
+            match FALL_OFFSETS
+                .iter()
+                .map(|&offset| grain + offset)
+                .find(|&next| self.is_free(next))
+            {
+                Some(next) => path.push(next),
+                None => {
+                    self.set(grain, Tile::Sand);
+                    grains += 1;
+                    path.pop();
+                }
+            }
+        }
+
+        grains
+    }
+}
+
+/// Draws the cave like the puzzle's diagrams, cropped to the columns holding rock or sand.
+impl fmt::Display for Cave {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let occupied = (0..self.tiles.cols())
+            .filter(|&col| self.tiles.iter_col(col).any(|tile| *tile != Tile::Air))
+            .map(|col| col as i32 + self.left);
+        let (left, right) = occupied.fold((SAND_SOURCE.x, SAND_SOURCE.x), |(left, right), x| {
+            (left.min(x), right.max(x))
+        });
+        let bottom = if self.floor {
+            self.lowest_rock + 1
+        } else {
+            self.lowest_rock
+        };
+
+        for y in 0..=bottom {
+            for x in left..=right {
+                let point = Point::new(x, y);
+                let symbol = match self.get(point) {
+                    Tile::Air if point == SAND_SOURCE => '+',
+                    Tile::Air => '.',
+                    Tile::Rock => '#',
+                    Tile::Sand => 'o',
+                };
+                write!(f, "{symbol}")?;
+            }
+            writeln!(f)?;
+        }
+
+        if self.floor {
+            writeln!(f, "{}", "#".repeat((right - left + 1) as usize))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn point(input: &str) -> PResult<'_, Point> {
+    map(
+        separated_pair(number::<u16>, tag(","), number::<u16>),
+        |(x, y)| Point::new(x.into(), y.into()),
+    )(input)
+}
+
+fn rock_path(input: &str) -> PResult<'_, Vec<Point>> {
+    map_res(separated_list1(tag(" -> "), point), |path| {
+        if path
+            .windows(2)
+            .all(|segment| segment[0].x == segment[1].x || segment[0].y == segment[1].y)
+        {
+            Ok(path)
+        } else {
+            Err("rock paths must be horizontal or vertical")
+        }
+    })(input)
+}
+
+#[aoc_generator(day14)]
+pub(crate) fn parse_input(input: &str) -> Result<Cave, AocError> {
+    let input = &normalize(input);
+
+    let paths = parse::run(14, lines(rock_path), input)?;
+
+    Ok(Cave::new(&paths))
+}
+
+#[aoc(day14, part1)]
+pub(crate) fn part1(cave: &Cave) -> usize {
+    cave.clone().pour()
+}
+
+#[aoc(day14, part2)]
+pub(crate) fn part2(cave: &Cave) -> usize {
+    let mut cave = cave.clone();
+    cave.floor = true;
+
+    cave.pour()
+}
+
+pub struct Day14;
+
+impl Puzzle for Day14 {
+    type Input = Cave;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, cave: &Self::Input) -> Answer {
+        part1(cave).into()
+    }
+
+    fn part2(&self, cave: &Self::Input) -> Answer {
+        part2(cave).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r"498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 24);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), 93);
+    }
+
+    #[test]
+    fn render_cave() {
+        let mut cave = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(
+            cave.to_string(),
+            r"......+...
+..........
+..........
+..........
+....#...##
+....#...#.
+..###...#.
+........#.
+........#.
+#########.
+"
+        );
+
+        cave.pour();
+        assert_eq!(
+            cave.to_string(),
+            r"......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########.
+"
+        );
+
+        let mut cave = parse_input(TEST_INPUT).unwrap();
+        cave.floor = true;
+        cave.pour();
+        assert_eq!(
+            cave.to_string(),
+            r"..........o..........
+.........ooo.........
+........ooooo........
+.......ooooooo.......
+......oo#ooo##o......
+.....ooo#ooo#ooo.....
+....oo###ooo#oooo....
+...oooo.oooo#ooooo...
+..oooooooooo#oooooo..
+.ooo#########ooooooo.
+ooooo.......ooooooooo
+#####################
+"
+        );
+    }
+
+    #[test]
+    fn parse_input_diagonal_path() {
+        assert_eq!(
+            parse_input("498,4 -> 498,6\n503,4 -> 502,5").err().unwrap().to_string(),
+            "day 14, line 2, column 1: rock paths must be horizontal or vertical, found `503,4 -> 502,5`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(Day14.solve(Part::One, &input).unwrap(), Answer::Number(24));
+        assert_eq!(Day14.solve(Part::Two, &input).unwrap(), Answer::Number(93));
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), 24);
+        assert_eq!(part2(&parse_input(&input).unwrap()), 93);
+    }
+}
@@ -0,0 +1,249 @@
+use crate::error::AocError;
+use crate::geometry::Point;
+use crate::input::normalize;
+use crate::parse::{self, lines, map, number, preceded, separated_pair, tag, PResult};
+use crate::puzzle::{Answer, Puzzle};
+use crate::y2022::day04::Area;
+use std::collections::HashSet;
+
+const ROW: i32 = 2_000_000;
+const SEARCH_SIZE: i32 = 4_000_000;
+
+pub struct Sensor {
+    position: Point,
+    beacon: Point,
+}
+
+impl Sensor {
+    fn parse(input: &str) -> PResult<'_, Sensor> {
+        map(
+            separated_pair(
+                preceded(tag("Sensor at "), position),
+                tag(": "),
+                preceded(tag("closest beacon is at "), position),
+            ),
+            |(position, beacon)| Sensor { position, beacon },
+        )(input)
+    }
+
+    /// No beacon other than the closest one can be within this distance of the sensor.
+    fn radius(&self) -> i32 {
+        self.position.manhattan_distance(self.beacon) as i32
+    }
+
+    /// The positions of `row` within the sensor's radius, if any.
+    fn coverage(&self, row: i32) -> Option<Area> {
+        let reach = self.radius() - (self.position.y - row).abs();
+
+        (reach >= 0).then(|| Area::new(self.position.x - reach, self.position.x + reach))
+    }
+
+    fn covers(&self, point: Point) -> bool {
+        self.position.manhattan_distance(point) as i32 <= self.radius()
+    }
+}
+
+fn position(input: &str) -> PResult<'_, Point> {
+    map(
+        separated_pair(
+            preceded(tag("x="), number),
+            tag(", "),
+            preceded(tag("y="), number),
+        ),
+        |(x, y)| Point::new(x, y),
+    )(input)
+}
+
+/// The merged positions of `row` covered by any sensor.
+fn row_coverage(sensors: &[Sensor], row: i32) -> Vec<Area> {
+    Area::merge(
+        sensors
+            .iter()
+            .filter_map(|sensor| sensor.coverage(row))
+            .collect(),
+    )
+}
+
+fn excluded_positions(sensors: &[Sensor], row: i32) -> u64 {
+    let coverage = row_coverage(sensors, row);
+    let beacons: HashSet<i32> = sensors
+        .iter()
+        .filter(|sensor| sensor.beacon.y == row)
+        .map(|sensor| sensor.beacon.x)
+        .filter(|&x| coverage.iter().any(|area| area.contains_id(x)))
+        .collect();
+
+    coverage.iter().map(Area::len).sum::<u64>() - beacons.len() as u64
+}
+
+/// The only position within `0..=size` in both coordinates no sensor covers.
+///
+/// Being the only such position, it lies just outside the radius of some sensors, so it is an
+/// intersection of the diagonal lines bordering their ranges. Those are of the form `x + y = a`
+/// and `x - y = b`, so only pairs of them are checked. A position on the edge of the search area
+/// need not be bordered on all sides, so if no intersection qualifies the rows are scanned.
+/// Returns `None` if several intersections qualify, or if the scan doesn't find exactly one
+/// uncovered position.
+fn distress_beacon(sensors: &[Sensor], size: i32) -> Option<Point> {
+    let in_search_area =
+        |point: &Point| (0..=size).contains(&point.x) && (0..=size).contains(&point.y);
+    let uncovered = |point: &Point| sensors.iter().all(|sensor| !sensor.covers(*point));
+
+    let mut ascending = HashSet::new();
+    let mut descending = HashSet::new();
+    for sensor in sensors {
+        let (x, y, border) = (sensor.position.x, sensor.position.y, sensor.radius() + 1);
+        ascending.extend([x + y - border, x + y + border]);
+        descending.extend([x - y - border, x - y + border]);
+    }
+
+    let intersections = ascending.iter().flat_map(|&a: &i32| {
+        descending
+            .iter()
+            .filter(move |&&b| (a + b) % 2 == 0)
+            .map(move |&b| Point::new((a + b) / 2, (a - b) / 2))
+    });
+
+    let mut candidates: Vec<Point> = intersections
+        .filter(in_search_area)
+        .filter(uncovered)
+        .collect();
+    candidates.sort();
+    candidates.dedup();
+    match candidates[..] {
+        [beacon] => return Some(beacon),
+        [] => {}
+        _ => return None,
+    }
+
+    let mut beacon = None;
+    for y in 0..=size {
+        let mut x = 0;
+        let coverage = row_coverage(sensors, y);
+        let ends = coverage.iter().map(|area| (area.from, area.to + 1));
+        for (from, next) in ends.chain([(size + 1, size + 1)]) {
+            // the positions from `x` up to the next covered one are not covered
+            for gap in x..from.min(size + 1) {
+                if beacon.replace(Point::new(gap, y)).is_some() {
+                    return None;
+                }
+            }
+            x = x.max(next);
+            if x > size {
+                break;
+            }
+        }
+    }
+
+    beacon
+}
+
+fn tuning_frequency(beacon: Point) -> u64 {
+    beacon.x as u64 * 4_000_000 + beacon.y as u64
+}
+
+#[aoc_generator(day15)]
+pub(crate) fn parse_input(input: &str) -> Result<Vec<Sensor>, AocError> {
+    let input = &normalize(input);
+
+    parse::run(15, lines(Sensor::parse), input)
+}
+
+#[aoc(day15, part1)]
+pub(crate) fn part1(sensors: &[Sensor]) -> u64 {
+    excluded_positions(sensors, ROW)
+}
+
+#[aoc(day15, part2)]
+pub(crate) fn part2(sensors: &[Sensor]) -> Option<u64> {
+    distress_beacon(sensors, SEARCH_SIZE).map(tuning_frequency)
+}
+
+pub struct Day15;
+
+impl Puzzle for Day15 {
+    type Input = Vec<Sensor>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, sensors: &Self::Input) -> Answer {
+        part1(sensors).into()
+    }
+
+    fn part2(&self, sensors: &Self::Input) -> Answer {
+        part2(sensors).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r"Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            excluded_positions(&parse_input(TEST_INPUT).unwrap(), 10),
+            26
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        let beacon = distress_beacon(&parse_input(TEST_INPUT).unwrap(), 20);
+
+        assert_eq!(beacon, Some(Point::new(14, 11)));
+        assert_eq!(beacon.map(tuning_frequency), Some(56000011));
+    }
+
+    #[test]
+    fn distress_beacon_in_corner() {
+        // covers everything but the corner (0, 0), which borders the sensor on one side only
+        let sensors = parse_input("Sensor at x=3, y=3: closest beacon is at x=3, y=8").unwrap();
+
+        assert_eq!(distress_beacon(&sensors, 3), Some(Point::new(0, 0)));
+    }
+
+    #[test]
+    fn no_single_distress_beacon() {
+        // leaves (0, 0), (0, 1) and (1, 0) uncovered
+        let sensors = parse_input("Sensor at x=3, y=3: closest beacon is at x=3, y=7").unwrap();
+        assert_eq!(distress_beacon(&sensors, 3), None);
+
+        assert_eq!(distress_beacon(&[], 3), None);
+        assert_eq!(distress_beacon(&[], 0), Some(Point::new(0, 0)));
+        assert_eq!(
+            Day15.solve(Part::Two, "").unwrap(),
+            Answer::from("no solution")
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+        let sensors = Solver::parse(&Day15, &input).unwrap();
+        let sensors: &Vec<Sensor> = sensors.downcast_ref().unwrap();
+
+        assert_eq!(excluded_positions(sensors, 10), 26);
+        assert_eq!(distress_beacon(sensors, 20), Some(Point::new(14, 11)));
+
+        assert_eq!(excluded_positions(&parse_input(&input).unwrap(), 10), 26);
+    }
+}
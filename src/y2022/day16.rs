@@ -0,0 +1,345 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{
+    self, alt, lines, number, preceded, separated_list1, tag, take_while1, PResult,
+};
+use crate::puzzle::{Answer, Puzzle};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+const START: &str = "AA";
+const ACTORS: [&str; 2] = ["you", "elephant"];
+
+/// A line of the scan, naming a valve, its flow rate and the valves its tunnels lead to.
+struct Scan<'a> {
+    name: &'a str,
+    rate: u32,
+    tunnels: Vec<&'a str>,
+}
+
+impl Scan<'_> {
+    fn parse(input: &str) -> PResult<'_, Scan<'_>> {
+        let (input, name) = preceded(tag("Valve "), valve_name)(input)?;
+        let (input, rate) = preceded(tag(" has flow rate="), number)(input)?;
+        let (input, tunnels) = preceded(
+            alt((
+                tag("; tunnels lead to valves "),
+                tag("; tunnel leads to valve "),
+            )),
+            separated_list1(tag(", "), valve_name),
+        )(input)?;
+
+        Ok((
+            input,
+            Scan {
+                name,
+                rate,
+                tunnels,
+            },
+        ))
+    }
+}
+
+fn valve_name(input: &str) -> PResult<'_, &str> {
+    take_while1(|c| c.is_ascii_uppercase(), "expected a valve name")(input)
+}
+
+/// The valves worth opening, plus the start, with the travel time between every two of them.
+/// The start is always the first valve.
+pub struct Volcano {
+    names: Vec<String>,
+    rates: Vec<u32>,
+    distances: Vec<Vec<u32>>,
+}
+
+/// The valves each actor opens, in order, and the pressure they release together.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Plan {
+    pub pressure: u32,
+    pub routes: Vec<Vec<String>>,
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (actor, route) in ACTORS.iter().zip(&self.routes) {
+            writeln!(f, "{actor}: {}", route.join(", "))?;
+        }
+        write!(f, "pressure released: {}", self.pressure)
+    }
+}
+
+impl Volcano {
+    /// The most pressure released by opening each set of valves within `minutes`, together with
+    /// the order of opening that achieves it, keyed by the bitmask of opened valves.
+    fn best_by_opened(&self, minutes: u32) -> HashMap<u64, (u32, Vec<usize>)> {
+        let mut best = HashMap::new();
+        self.explore(0, minutes, 0, 0, &mut Vec::new(), &mut best);
+        best
+    }
+
+    fn explore(
+        &self,
+        valve: usize,
+        time_left: u32,
+        opened: u64,
+        pressure: u32,
+        route: &mut Vec<usize>,
+        best: &mut HashMap<u64, (u32, Vec<usize>)>,
+    ) {
+        match best.get(&opened) {
+            Some((released, _)) if *released >= pressure => {}
+            _ => {
+                best.insert(opened, (pressure, route.clone()));
+            }
+        }
+
+        for next in 0..self.rates.len() {
+            let cost = self.distances[valve][next].saturating_add(1);
+            if self.rates[next] == 0 || opened & (1 << next) != 0 || cost >= time_left {
+                continue;
+            }
+
+            let time_left = time_left - cost;
+            route.push(next);
+            self.explore(
+                next,
+                time_left,
+                opened | (1 << next),
+                pressure + self.rates[next] * time_left,
+                route,
+                best,
+            );
+            route.pop();
+        }
+    }
+
+    fn route_names(&self, route: &[usize]) -> Vec<String> {
+        route
+            .iter()
+            .map(|&valve| self.names[valve].clone())
+            .collect()
+    }
+
+    /// The best plan for opening valves alone within `minutes`.
+    pub fn plan_alone(&self, minutes: u32) -> Plan {
+        let best = self.best_by_opened(minutes);
+        let (pressure, route) = best
+            .into_values()
+            .max_by_key(|(pressure, _)| *pressure)
+            .unwrap_or_default();
+
+        Plan {
+            pressure,
+            routes: vec![self.route_names(&route)],
+        }
+    }
+
+    /// The best plan for opening valves together with an elephant within `minutes`. Neither
+    /// opens a valve the other opens, so the best plan is the best pair of disjoint sets of
+    /// valves opened alone.
+    pub fn plan_with_elephant(&self, minutes: u32) -> Plan {
+        let mut best: Vec<_> = self.best_by_opened(minutes).into_iter().collect();
+        best.sort_unstable_by_key(|(_, (pressure, _))| std::cmp::Reverse(*pressure));
+
+        let mut plan = (0, 0, 0);
+        for (i, (yours, (your_pressure, _))) in best.iter().enumerate() {
+            if your_pressure * 2 < plan.0 {
+                break;
+            }
+            for (j, (elephants, (elephant_pressure, _))) in best.iter().enumerate().skip(i) {
+                if your_pressure + elephant_pressure <= plan.0 {
+                    break;
+                }
+                if yours & elephants == 0 {
+                    plan = (your_pressure + elephant_pressure, i, j);
+                }
+            }
+        }
+
+        let (pressure, yours, elephants) = plan;
+        let (_, (_, your_route)) = &best[yours];
+        let (_, (_, elephant_route)) = &best[elephants];
+
+        Plan {
+            pressure,
+            routes: vec![
+                self.route_names(your_route),
+                self.route_names(elephant_route),
+            ],
+        }
+    }
+}
+
+/// The number of minutes from `start` to every valve, or `u32::MAX` if it can't be reached.
+fn travel_times(tunnels: &[Vec<usize>], start: usize) -> Vec<u32> {
+    let mut times = vec![u32::MAX; tunnels.len()];
+    let mut queue = VecDeque::from(vec![start]);
+    times[start] = 0;
+
+    while let Some(valve) = queue.pop_front() {
+        for &neighbor in &tunnels[valve] {
+            if times[neighbor] == u32::MAX {
+                times[neighbor] = times[valve] + 1;
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    times
+}
+
+#[aoc_generator(day16)]
+pub(crate) fn parse_input(input: &str) -> Result<Volcano, AocError> {
+    let input = &normalize(input);
+
+    let scans = parse::run(16, lines(Scan::parse), input)?;
+    let indices: HashMap<&str, usize> = scans
+        .iter()
+        .enumerate()
+        .map(|(index, scan)| (scan.name, index))
+        .collect();
+
+    let tunnels = scans
+        .iter()
+        .map(|scan| {
+            scan.tunnels
+                .iter()
+                .map(|tunnel| {
+                    indices
+                        .get(tunnel)
+                        .copied()
+                        .ok_or_else(|| AocError::at(16, input, tunnel, "unknown valve"))
+                })
+                .collect::<Result<Vec<usize>, AocError>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let start = *indices
+        .get(START)
+        .ok_or_else(|| AocError::at(16, input, &input[input.len()..], "missing valve `AA`"))?;
+    let mut relevant = vec![start];
+    relevant.extend((0..scans.len()).filter(|&valve| valve != start && scans[valve].rate > 0));
+    if relevant.len() > u64::BITS as usize {
+        return Err(AocError::at(
+            16,
+            input,
+            scans[relevant[64]].name,
+            "too many valves with flow",
+        ));
+    }
+
+    let distances = relevant
+        .iter()
+        .map(|&from| {
+            let times = travel_times(&tunnels, from);
+            relevant.iter().map(|&to| times[to]).collect()
+        })
+        .collect();
+
+    Ok(Volcano {
+        names: relevant
+            .iter()
+            .map(|&valve| scans[valve].name.to_owned())
+            .collect(),
+        rates: relevant.iter().map(|&valve| scans[valve].rate).collect(),
+        distances,
+    })
+}
+
+#[aoc(day16, part1)]
+pub(crate) fn part1(volcano: &Volcano) -> u32 {
+    volcano.plan_alone(30).pressure
+}
+
+#[aoc(day16, part2)]
+pub(crate) fn part2(volcano: &Volcano) -> u32 {
+    volcano.plan_with_elephant(26).pressure
+}
+
+pub struct Day16;
+
+impl Puzzle for Day16 {
+    type Input = Volcano;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, volcano: &Self::Input) -> Answer {
+        part1(volcano).into()
+    }
+
+    fn part2(&self, volcano: &Self::Input) -> Answer {
+        part2(volcano).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r"Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 1651);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), 1707);
+    }
+
+    #[test]
+    fn opening_order() {
+        let volcano = parse_input(TEST_INPUT).unwrap();
+
+        let plan = volcano.plan_alone(30);
+        assert_eq!(
+            plan.to_string(),
+            "you: DD, BB, JJ, HH, EE, CC\npressure released: 1651"
+        );
+
+        let mut routes = volcano.plan_with_elephant(26).routes;
+        routes.sort();
+        assert_eq!(routes, vec![vec!["DD", "HH", "EE"], vec!["JJ", "BB", "CC"]]);
+    }
+
+    #[test]
+    fn parse_input_unknown_valve() {
+        assert_eq!(
+            parse_input("Valve AA has flow rate=0; tunnel leads to valve BB")
+                .err()
+                .unwrap()
+                .to_string(),
+            "day 16, line 1, column 49: unknown valve, found `BB`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(
+            Day16.solve(Part::One, &input).unwrap(),
+            Answer::Number(1651)
+        );
+        assert_eq!(
+            Day16.solve(Part::Two, &input).unwrap(),
+            Answer::Number(1707)
+        );
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), 1651);
+        assert_eq!(part2(&parse_input(&input).unwrap()), 1707);
+    }
+}
@@ -0,0 +1,291 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{self, many_to_end, map_res, satisfy, PResult};
+use crate::puzzle::{Answer, Puzzle};
+use std::collections::HashMap;
+
+const WIDTH: usize = 7;
+const LEFT_WALL: u8 = 1 << (WIDTH - 1);
+const RIGHT_WALL: u8 = 1;
+
+/// The rocks in the order they fall, as rows from the bottom up. Bit 6 is the leftmost column
+/// and every rock starts two columns away from the left wall.
+const ROCKS: [&[u8]; 5] = [
+    &[0b0011110],
+    &[0b0001000, 0b0011100, 0b0001000],
+    &[0b0011100, 0b0000100, 0b0000100],
+    &[0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    &[0b0011000, 0b0011000],
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+impl Jet {
+    fn parse(input: &str) -> PResult<'_, Jet> {
+        map_res(satisfy(|_| true, "expected a jet"), |jet| match jet {
+            '<' => Ok(Jet::Left),
+            '>' => Ok(Jet::Right),
+            _ => Err("unknown jet"),
+        })(input)
+    }
+}
+
+/// The chamber with the rocks that came to rest in it, as one bitmask per row from the floor up.
+pub struct Chamber<'a> {
+    jets: &'a [Jet],
+    rows: Vec<u8>,
+    rocks: usize,
+    jet: usize,
+}
+
+impl<'a> Chamber<'a> {
+    pub fn new(jets: &'a [Jet]) -> Chamber<'a> {
+        Chamber {
+            jets,
+            rows: Vec::new(),
+            rocks: 0,
+            jet: 0,
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn collides(&self, rock: &[u8], bottom: usize) -> bool {
+        rock.iter()
+            .zip(self.rows.iter().skip(bottom))
+            .any(|(rock_row, row)| rock_row & row != 0)
+    }
+
+    /// Lets the next rock fall until it comes to rest.
+    pub fn drop_rock(&mut self) {
+        let mut rock = ROCKS[self.rocks % ROCKS.len()].to_vec();
+        let mut bottom = self.height() + 3;
+        self.rocks += 1;
+
+        loop {
+            let jet = self.jets[self.jet];
+            self.jet = (self.jet + 1) % self.jets.len();
+
+            let pushed: Vec<u8> = match jet {
+                Jet::Left if rock.iter().all(|row| row & LEFT_WALL == 0) => {
+                    rock.iter().map(|row| row << 1).collect()
+                }
+                Jet::Right if rock.iter().all(|row| row & RIGHT_WALL == 0) => {
+                    rock.iter().map(|row| row >> 1).collect()
+                }
+                _ => rock.clone(),
+            };
+            if !self.collides(&pushed, bottom) {
+                rock = pushed;
+            }
+
+            if bottom == 0 || self.collides(&rock, bottom - 1) {
+                break;
+            }
+            bottom -= 1;
+        }
+
+        let top = bottom + rock.len();
+        if self.rows.len() < top {
+            self.rows.resize(top, 0);
+        }
+        for (row, rock_row) in self.rows[bottom..top].iter_mut().zip(rock) {
+            *row |= rock_row;
+        }
+    }
+
+    /// How far below the top of the tower the highest rock of each column is, which together with
+    /// the next rock and jet decides how the tower grows from here.
+    fn profile(&self) -> [usize; WIDTH] {
+        let mut profile = [self.height(); WIDTH];
+        for (column, depth) in profile.iter_mut().enumerate() {
+            let mask = LEFT_WALL >> column;
+            if let Some(found) = self.rows.iter().rev().position(|row| row & mask != 0) {
+                *depth = found;
+            }
+        }
+        profile
+    }
+
+    /// Draws the top `rows` rows of the chamber like the puzzle's diagrams, with the floor if it
+    /// is among them.
+    pub fn render(&self, rows: usize) -> String {
+        let mut drawing = String::new();
+
+        for row in self.rows.iter().rev().take(rows) {
+            drawing.push('|');
+            for column in 0..WIDTH {
+                drawing.push(if row & (LEFT_WALL >> column) != 0 {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            drawing.push_str("|\n");
+        }
+        if rows > self.height() {
+            drawing.push_str(&format!("+{}+\n", "-".repeat(WIDTH)));
+        }
+
+        drawing
+    }
+}
+
+/// The height of the tower after `rocks` rocks. The tower grows in cycles once the next rock,
+/// the next jet and the shape of its top repeat, so as soon as they do, whole cycles are skipped.
+fn tower_height(jets: &[Jet], rocks: usize) -> usize {
+    let mut chamber = Chamber::new(jets);
+    let mut seen = HashMap::new();
+    let mut skipped_height = 0;
+
+    while chamber.rocks < rocks {
+        chamber.drop_rock();
+
+        if skipped_height > 0 {
+            continue;
+        }
+
+        let state = (chamber.rocks % ROCKS.len(), chamber.jet, chamber.profile());
+        if let Some((previous_rocks, previous_height)) =
+            seen.insert(state, (chamber.rocks, chamber.height()))
+        {
+            let cycle_length = chamber.rocks - previous_rocks;
+            let cycle_height = chamber.height() - previous_height;
+            let cycles = (rocks - chamber.rocks) / cycle_length;
+
+            chamber.rocks += cycles * cycle_length;
+            skipped_height = cycles * cycle_height;
+        }
+    }
+
+    chamber.height() + skipped_height
+}
+
+#[aoc_generator(day17)]
+pub(crate) fn parse_input(input: &str) -> Result<Vec<Jet>, AocError> {
+    let input = &normalize(input);
+
+    let jets = parse::run(17, many_to_end(Jet::parse), input)?;
+    if jets.is_empty() {
+        return Err(AocError::at(17, input, input, "expected a jet"));
+    }
+
+    Ok(jets)
+}
+
+#[aoc(day17, part1)]
+pub(crate) fn part1(jets: &[Jet]) -> usize {
+    tower_height(jets, 2022)
+}
+
+#[aoc(day17, part2)]
+pub(crate) fn part2(jets: &[Jet]) -> usize {
+    tower_height(jets, 1_000_000_000_000)
+}
+
+pub struct Day17;
+
+impl Puzzle for Day17 {
+    type Input = Vec<Jet>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, jets: &Self::Input) -> Answer {
+        part1(jets).into()
+    }
+
+    fn part2(&self, jets: &Self::Input) -> Answer {
+        part2(jets).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 3068);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), 1514285714288);
+    }
+
+    #[test]
+    fn cycles_match_simulation() {
+        let jets = parse_input(TEST_INPUT).unwrap();
+        let mut chamber = Chamber::new(&jets);
+
+        for rocks in 1..=500 {
+            chamber.drop_rock();
+            assert_eq!(tower_height(&jets, rocks), chamber.height());
+        }
+    }
+
+    #[test]
+    fn render_chamber() {
+        let jets = parse_input(TEST_INPUT).unwrap();
+        let mut chamber = Chamber::new(&jets);
+        for _ in 0..10 {
+            chamber.drop_rock();
+        }
+
+        assert_eq!(chamber.height(), 17);
+        assert_eq!(chamber.render(3), "|....#..|\n|....#..|\n|....##.|\n");
+        assert_eq!(
+            chamber.render(20),
+            r"|....#..|
+|....#..|
+|....##.|
+|##..##.|
+|######.|
+|.###...|
+|..#....|
+|.####..|
+|....##.|
+|....##.|
+|....#..|
+|..#.#..|
+|..#.#..|
+|#####..|
+|..###..|
+|...#...|
+|..####.|
++-------+
+"
+        );
+    }
+
+    #[test]
+    fn parse_input_unknown_jet() {
+        assert_eq!(
+            parse_input(">><v").err().unwrap().to_string(),
+            "day 17, line 1, column 4: unknown jet, found `v`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.to_owned() + "\r\n";
+
+        assert_eq!(
+            Day17.solve(Part::One, &input).unwrap(),
+            Answer::Number(3068)
+        );
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), 3068);
+    }
+}
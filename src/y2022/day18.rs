@@ -0,0 +1,141 @@
+use crate::error::AocError;
+use crate::geometry::Point3;
+use crate::input::normalize;
+use crate::parse::{self, lines, map, number, separated_pair, tag, PResult};
+use crate::puzzle::{Answer, Puzzle};
+use std::collections::{HashSet, VecDeque};
+
+pub struct Droplet {
+    cubes: HashSet<Point3>,
+}
+
+impl Droplet {
+    /// The six cubes sharing a face with `cube`, and whether each of them is lava.
+    fn neighbor_cubes(&self, cube: Point3) -> Vec<(Point3, bool)> {
+        cube.neighbors6()
+            .map(|neighbor| (neighbor, self.cubes.contains(&neighbor)))
+            .collect()
+    }
+}
+
+fn cube(input: &str) -> PResult<'_, Point3> {
+    map(
+        separated_pair(number, tag(","), separated_pair(number, tag(","), number)),
+        |(x, (y, z))| Point3::new(x, y, z),
+    )(input)
+}
+
+#[aoc_generator(day18)]
+pub(crate) fn parse_input(input: &str) -> Result<Droplet, AocError> {
+    let input = &normalize(input);
+
+    let cubes = parse::run(18, lines(cube), input)?;
+
+    Ok(Droplet {
+        cubes: cubes.into_iter().collect(),
+    })
+}
+
+#[aoc(day18, part1)]
+pub(crate) fn part1(droplet: &Droplet) -> usize {
+    droplet
+        .cubes
+        .iter()
+        .flat_map(|&cube| droplet.neighbor_cubes(cube))
+        .filter(|(_, lava)| !lava)
+        .count()
+}
+
+/// Floods the air around the droplet, starting just outside its bounding box, and counts the
+/// faces of lava the water runs into. Air pockets inside the droplet are never reached.
+#[aoc(day18, part2)]
+pub(crate) fn part2(droplet: &Droplet) -> usize {
+    let Some((min, max)) = Point3::bounds(droplet.cubes.iter().copied()) else {
+        return 0;
+    };
+    let (min, max) = (min - Point3::new(1, 1, 1), max + Point3::new(1, 1, 1));
+
+    let mut queue = VecDeque::from(vec![min]);
+    let mut visited: HashSet<Point3> = HashSet::from([min]);
+    let mut exterior_faces = 0;
+
+    while let Some(current_position) = queue.pop_front() {
+        for (neighbor_position, lava) in droplet.neighbor_cubes(current_position) {
+            if lava {
+                exterior_faces += 1;
+            } else if neighbor_position.within(min, max) && visited.insert(neighbor_position) {
+                queue.push_back(neighbor_position);
+            }
+        }
+    }
+
+    exterior_faces
+}
+
+pub struct Day18;
+
+impl Puzzle for Day18 {
+    type Input = Droplet;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, droplet: &Self::Input) -> Answer {
+        part1(droplet).into()
+    }
+
+    fn part2(&self, droplet: &Self::Input) -> Answer {
+        part2(droplet).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r"2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 64);
+        assert_eq!(part1(&parse_input("1,1,1\n2,1,1").unwrap()), 10);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), 58);
+    }
+
+    #[test]
+    fn parse_input_missing_coordinate() {
+        assert_eq!(
+            parse_input("2,2,2\n1,2").err().unwrap().to_string(),
+            "day 18, line 2, column 4: expected `,`, found end of line"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(Day18.solve(Part::One, &input).unwrap(), Answer::Number(64));
+        assert_eq!(Day18.solve(Part::Two, &input).unwrap(), Answer::Number(58));
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), 64);
+        assert_eq!(part2(&parse_input(&input).unwrap()), 58);
+    }
+}
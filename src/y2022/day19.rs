@@ -0,0 +1,334 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{self, lines, number, preceded, tag, PResult};
+use crate::puzzle::{Answer, Puzzle};
+use std::fmt;
+use std::thread;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Robot {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+impl Robot {
+    /// Most valuable first, which finds good plans early and lets the bound prune more.
+    const ALL: [Robot; 4] = [Robot::Geode, Robot::Obsidian, Robot::Clay, Robot::Ore];
+}
+
+impl fmt::Display for Robot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Robot::Ore => "ore",
+            Robot::Clay => "clay",
+            Robot::Obsidian => "obsidian",
+            Robot::Geode => "geode",
+        };
+        write!(f, "{name}-collecting robot")
+    }
+}
+
+pub struct Blueprint {
+    id: u32,
+    /// What each robot costs in ore, clay and obsidian, indexed by [`Robot`].
+    costs: [[u32; 3]; 4],
+}
+
+/// The robots to build, each with the minute its construction starts, and the geodes they crack.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Plan {
+    pub geodes: u32,
+    pub builds: Vec<(u32, Robot)>,
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (minute, robot) in &self.builds {
+            writeln!(f, "minute {minute}: build a {robot}")?;
+        }
+        write!(f, "{} geodes", self.geodes)
+    }
+}
+
+/// A point of the search, right after a robot was built.
+#[derive(Clone, Copy)]
+struct State {
+    time_left: u32,
+    robots: [u32; 3],
+    resources: [u32; 3],
+    geodes: u32,
+}
+
+impl Blueprint {
+    fn parse(input: &str) -> PResult<'_, Blueprint> {
+        let (input, id) = preceded(tag("Blueprint "), number)(input)?;
+        let (input, ore_robot) = preceded(tag(": Each ore robot costs "), number)(input)?;
+        let (input, clay_robot) = preceded(tag(" ore. Each clay robot costs "), number)(input)?;
+        let (input, obsidian_robot_ore) =
+            preceded(tag(" ore. Each obsidian robot costs "), number)(input)?;
+        let (input, obsidian_robot_clay) = preceded(tag(" ore and "), number)(input)?;
+        let (input, geode_robot_ore) =
+            preceded(tag(" clay. Each geode robot costs "), number)(input)?;
+        let (input, geode_robot_obsidian) = preceded(tag(" ore and "), number)(input)?;
+        let (input, _) = tag(" obsidian.")(input)?;
+
+        Ok((
+            input,
+            Blueprint {
+                id,
+                costs: [
+                    [ore_robot, 0, 0],
+                    [clay_robot, 0, 0],
+                    [obsidian_robot_ore, obsidian_robot_clay, 0],
+                    [geode_robot_ore, 0, geode_robot_obsidian],
+                ],
+            },
+        ))
+    }
+
+    /// The plan cracking the most geodes within `minutes`.
+    pub fn best_plan(&self, minutes: u32) -> Plan {
+        // a factory builds one robot per minute, so more robots of a kind than the most of its
+        // resource any robot costs are of no use
+        let mut caps = [0; 3];
+        for cost in &self.costs {
+            for (cap, amount) in caps.iter_mut().zip(cost) {
+                *cap = (*cap).max(*amount);
+            }
+        }
+
+        let start = State {
+            time_left: minutes,
+            robots: [1, 0, 0],
+            resources: [0; 3],
+            geodes: 0,
+        };
+        let mut best = Plan::default();
+        self.search(minutes, &caps, start, &mut Vec::new(), &mut best);
+
+        best
+    }
+
+    /// Branches on the next robot to build, waiting for its resources as long as needed.
+    fn search(
+        &self,
+        minutes: u32,
+        caps: &[u32; 3],
+        state: State,
+        builds: &mut Vec<(u32, Robot)>,
+        best: &mut Plan,
+    ) {
+        if state.geodes > best.geodes {
+            *best = Plan {
+                geodes: state.geodes,
+                builds: builds.clone(),
+            };
+        }
+
+        // even a geode robot built in every remaining minute can't beat the best plan
+        let t = state.time_left;
+        if state.geodes + t.saturating_sub(1) * t / 2 <= best.geodes {
+            return;
+        }
+
+        for robot in Robot::ALL {
+            let kind = robot as usize;
+            if robot != Robot::Geode && state.robots[kind] >= caps[kind] {
+                continue;
+            }
+
+            let Some(wait) = self.wait_for(robot, &state) else {
+                continue;
+            };
+            if wait + 1 >= state.time_left {
+                continue;
+            }
+
+            let time_left = state.time_left - wait - 1;
+            let mut next = State { time_left, ..state };
+            for resource in 0..3 {
+                next.resources[resource] += state.robots[resource] * (wait + 1);
+                next.resources[resource] -= self.costs[kind][resource];
+            }
+            match robot {
+                Robot::Geode => next.geodes += time_left,
+                _ => next.robots[kind] += 1,
+            }
+
+            builds.push((minutes - state.time_left + wait + 1, robot));
+            self.search(minutes, caps, next, builds, best);
+            builds.pop();
+        }
+    }
+
+    /// The minutes to wait until `robot` can be afforded, or `None` if no robot collects a
+    /// resource it needs yet.
+    fn wait_for(&self, robot: Robot, state: &State) -> Option<u32> {
+        let mut wait = 0;
+        for resource in 0..3 {
+            let missing =
+                self.costs[robot as usize][resource].saturating_sub(state.resources[resource]);
+            if missing > 0 {
+                if state.robots[resource] == 0 {
+                    return None;
+                }
+                wait = wait.max(missing.div_ceil(state.robots[resource]));
+            }
+        }
+        Some(wait)
+    }
+}
+
+/// The best plan of each blueprint, with the blueprints spread over the available cores.
+pub fn best_plans(blueprints: &[Blueprint], minutes: u32) -> Vec<Plan> {
+    let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+    let chunk_size = blueprints.len().div_ceil(threads).max(1);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = blueprints
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|blueprint| blueprint.best_plan(minutes))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    })
+}
+
+#[aoc_generator(day19)]
+pub(crate) fn parse_input(input: &str) -> Result<Vec<Blueprint>, AocError> {
+    let input = &normalize(input);
+
+    parse::run(19, lines(Blueprint::parse), input)
+}
+
+#[aoc(day19, part1)]
+pub(crate) fn part1(blueprints: &[Blueprint]) -> u32 {
+    blueprints
+        .iter()
+        .zip(best_plans(blueprints, 24))
+        .map(|(blueprint, plan)| blueprint.id * plan.geodes)
+        .sum()
+}
+
+#[aoc(day19, part2)]
+pub(crate) fn part2(blueprints: &[Blueprint]) -> u32 {
+    let first = &blueprints[..blueprints.len().min(3)];
+
+    best_plans(first, 32)
+        .iter()
+        .map(|plan| plan.geodes)
+        .product()
+}
+
+pub struct Day19;
+
+impl Puzzle for Day19 {
+    type Input = Vec<Blueprint>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, blueprints: &Self::Input) -> Answer {
+        part1(blueprints).into()
+    }
+
+    fn part2(&self, blueprints: &Self::Input) -> Answer {
+        part2(blueprints).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r"Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
+
+    /// Follows `plan` minute by minute and returns the geodes cracked.
+    fn replay(blueprint: &Blueprint, plan: &Plan, minutes: u32) -> u32 {
+        let mut robots = [1, 0, 0, 0];
+        let mut resources = [0; 4];
+
+        for minute in 1..=minutes {
+            let build = plan
+                .builds
+                .iter()
+                .find(|(start, _)| *start == minute)
+                .map(|(_, robot)| *robot as usize);
+            if let Some(kind) = build {
+                for (amount, cost) in resources.iter_mut().zip(blueprint.costs[kind]) {
+                    *amount -= cost;
+                }
+            }
+            for (amount, count) in resources.iter_mut().zip(robots) {
+                *amount += count;
+            }
+            if let Some(kind) = build {
+                robots[kind] += 1;
+            }
+        }
+
+        resources[Robot::Geode as usize]
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 33);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), 56 * 62);
+    }
+
+    #[test]
+    fn build_order() {
+        let blueprints = parse_input(TEST_INPUT).unwrap();
+        let plans = best_plans(&blueprints, 24);
+
+        assert_eq!(
+            plans.iter().map(|plan| plan.geodes).collect::<Vec<_>>(),
+            vec![9, 12]
+        );
+        for (blueprint, plan) in blueprints.iter().zip(&plans) {
+            assert_eq!(replay(blueprint, plan, 24), plan.geodes);
+        }
+        assert!(plans[0]
+            .to_string()
+            .starts_with("minute 3: build a clay-collecting robot\n"));
+    }
+
+    #[test]
+    fn parse_input_missing_cost() {
+        assert_eq!(
+            parse_input("Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs ore.")
+                .err()
+                .unwrap()
+                .to_string(),
+            "day 19, line 1, column 64: expected a number, found `ore`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(Day19.solve(Part::One, &input).unwrap(), Answer::Number(33));
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), 33);
+    }
+}
@@ -0,0 +1,235 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{self, lines, number};
+use crate::puzzle::{Answer, Puzzle};
+
+const DECRYPTION_KEY: i64 = 811_589_153;
+const GROVE_OFFSETS: [usize; 3] = [1000, 2000, 3000];
+
+/// A list of the indices `0..n`, split into blocks of about `sqrt(n)` items so that finding,
+/// removing and inserting an item takes `O(sqrt(n))` instead of shifting the whole list.
+struct BlockList {
+    blocks: Vec<Vec<usize>>,
+    /// The block each item is in.
+    block_of: Vec<usize>,
+    block_size: usize,
+}
+
+impl BlockList {
+    fn new(len: usize) -> BlockList {
+        let mut list = BlockList {
+            blocks: vec![(0..len).collect()],
+            block_of: vec![0; len],
+            block_size: len.isqrt().max(1),
+        };
+        list.rebuild();
+        list
+    }
+
+    /// Splits the items into blocks of equal size again, once inserts made one too large.
+    fn rebuild(&mut self) {
+        let items: Vec<usize> = self.blocks.concat();
+        self.blocks = items
+            .chunks(self.block_size)
+            .map(<[usize]>::to_vec)
+            .collect();
+
+        for (block, items) in self.blocks.iter().enumerate() {
+            for &item in items {
+                self.block_of[item] = block;
+            }
+        }
+    }
+
+    /// Removes `item` and returns the position it had.
+    fn remove(&mut self, item: usize) -> usize {
+        let block = self.block_of[item];
+        let offset = self.blocks[block].iter().position(|&i| i == item).unwrap();
+        self.blocks[block].remove(offset);
+
+        self.blocks[..block].iter().map(Vec::len).sum::<usize>() + offset
+    }
+
+    fn insert(&mut self, position: usize, item: usize) {
+        let mut offset = position;
+        let mut block = 0;
+        while block + 1 < self.blocks.len() && offset > self.blocks[block].len() {
+            offset -= self.blocks[block].len();
+            block += 1;
+        }
+
+        self.blocks[block].insert(offset, item);
+        self.block_of[item] = block;
+        if self.blocks[block].len() > 2 * self.block_size {
+            self.rebuild();
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.blocks.iter().flatten().copied()
+    }
+}
+
+/// Mixes `numbers` `rounds` times, moving each number in its original order as many positions
+/// forward or backward as its value, and returns the numbers in their final circular order.
+pub fn mix(numbers: &[i64], rounds: usize) -> Vec<i64> {
+    let len = numbers.len();
+    let mut list = BlockList::new(len);
+
+    if len > 1 {
+        for _ in 0..rounds {
+            for (item, number) in numbers.iter().enumerate() {
+                // the moving number isn't part of the circle it moves through
+                let position = list.remove(item) as i64;
+                let position = (position + number).rem_euclid(len as i64 - 1);
+                list.insert(position as usize, item);
+            }
+        }
+    }
+
+    list.iter().map(|item| numbers[item]).collect()
+}
+
+fn grove_coordinates(numbers: &[i64], rounds: usize) -> i64 {
+    let mixed = mix(numbers, rounds);
+    let zero = mixed.iter().position(|&number| number == 0).unwrap();
+
+    GROVE_OFFSETS
+        .iter()
+        .map(|offset| mixed[(zero + offset) % mixed.len()])
+        .sum()
+}
+
+#[aoc_generator(day20)]
+pub(crate) fn parse_input(input: &str) -> Result<Vec<i64>, AocError> {
+    let input = &normalize(input);
+
+    let numbers: Vec<i64> = parse::run(20, lines(number), input)?;
+    if !numbers.contains(&0) {
+        let end_of_input = &input[input.trim_end().len()..];
+        return Err(AocError::at(20, input, end_of_input, "missing `0`"));
+    }
+
+    Ok(numbers)
+}
+
+#[aoc(day20, part1)]
+pub(crate) fn part1(numbers: &[i64]) -> i64 {
+    grove_coordinates(numbers, 1)
+}
+
+#[aoc(day20, part2)]
+pub(crate) fn part2(numbers: &[i64]) -> i64 {
+    let decrypted: Vec<i64> = numbers
+        .iter()
+        .map(|number| number * DECRYPTION_KEY)
+        .collect();
+
+    grove_coordinates(&decrypted, 10)
+}
+
+pub struct Day20;
+
+impl Puzzle for Day20 {
+    type Input = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> Answer {
+        part1(numbers).into()
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> Answer {
+        part2(numbers).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r"1
+2
+-3
+3
+-2
+0
+4";
+
+    /// Mixes by shifting a plain `Vec`, the way the puzzle describes it.
+    fn mix_naively(numbers: &[i64], rounds: usize) -> Vec<i64> {
+        let len = numbers.len() as i64;
+        let mut order: Vec<usize> = (0..numbers.len()).collect();
+
+        for _ in 0..rounds {
+            for (item, number) in numbers.iter().enumerate() {
+                let position = order.iter().position(|&i| i == item).unwrap();
+                order.remove(position);
+                let position = (position as i64 + number).rem_euclid(len - 1);
+                order.insert(position as usize, item);
+            }
+        }
+
+        order.into_iter().map(|item| numbers[item]).collect()
+    }
+
+    /// Rotates a circular list to start at its first zero.
+    fn from_zero(numbers: Vec<i64>) -> Vec<i64> {
+        let zero = numbers.iter().position(|&number| number == 0).unwrap();
+        [&numbers[zero..], &numbers[..zero]].concat()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 3);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), 1623178306);
+    }
+
+    #[test]
+    fn mix_example() {
+        let numbers = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(from_zero(mix(&numbers, 1)), vec![0, 3, -2, 1, 2, -3, 4]);
+    }
+
+    #[test]
+    fn mix_with_duplicates() {
+        let numbers: Vec<i64> = (0..200).map(|i: i64| (i * 7919 % 61) - 30).collect();
+
+        for rounds in [1, 3] {
+            assert_eq!(
+                from_zero(mix(&numbers, rounds)),
+                from_zero(mix_naively(&numbers, rounds))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_input_missing_zero() {
+        assert_eq!(
+            parse_input("1\n2\n-3").err().unwrap().to_string(),
+            "day 20, line 3, column 3: missing `0`, found end of line"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(Day20.solve(Part::One, &input).unwrap(), Answer::Number(3));
+        assert_eq!(
+            Day20.solve(Part::Two, &input).unwrap(),
+            Answer::Number(1623178306)
+        );
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), 3);
+        assert_eq!(part2(&parse_input(&input).unwrap()), 1623178306);
+    }
+}
@@ -0,0 +1,416 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{
+    self, alt, lines, map, number, preceded, separated_pair, space1, tag, take_while1, PResult,
+};
+use crate::puzzle::{Answer, Puzzle};
+use crate::y2022::day11::Operator;
+use std::collections::HashMap;
+use std::fmt;
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+/// A monkey's job, referring to other monkeys by `M`.
+#[derive(Clone, Copy, Debug)]
+enum Job<M> {
+    Number(i64),
+    Operation(M, Operator, M),
+}
+
+impl Job<&str> {
+    fn parse(input: &str) -> PResult<'_, Job<&str>> {
+        alt((
+            map(number, Job::Number),
+            map(
+                separated_pair(
+                    monkey_name,
+                    space1,
+                    separated_pair(Operator::parse, space1, monkey_name),
+                ),
+                |(lhs, (operator, rhs))| Job::Operation(lhs, operator, rhs),
+            ),
+        ))(input)
+    }
+}
+
+fn monkey_name(input: &str) -> PResult<'_, &str> {
+    take_while1(|c| c.is_ascii_lowercase(), "expected a monkey name")(input)
+}
+
+fn monkey(input: &str) -> PResult<'_, (&str, Job<&str>)> {
+    separated_pair(monkey_name, tag(":"), preceded(space1, Job::parse))(input)
+}
+
+/// What a monkey yells, with the numbers every monkey but the human depends on worked out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expression {
+    Human,
+    Number(i64),
+    Operation(Box<Expression>, Operator, Box<Expression>),
+}
+
+impl Expression {
+    /// The value of the human that makes this expression equal `result`, found by undoing each
+    /// operation on the way down to the human.
+    fn solve(&self, result: i64) -> Option<i64> {
+        match self {
+            Expression::Human => Some(result),
+            Expression::Number(_) => None,
+            Expression::Operation(lhs, operator, rhs) => match (lhs.as_ref(), rhs.as_ref()) {
+                (lhs, Expression::Number(rhs)) => lhs.solve(operator.solve_lhs(result, *rhs)?),
+                (Expression::Number(lhs), rhs) => rhs.solve(operator.solve_rhs(result, *lhs)?),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expression::Human => write!(f, "{HUMAN}"),
+            Expression::Number(number) => write!(f, "{number}"),
+            Expression::Operation(lhs, operator, rhs) => write!(f, "({lhs} {operator} {rhs})"),
+        }
+    }
+}
+
+/// The equation `root` checks in part two, with both of its operands as expressions.
+pub struct Equation {
+    pub lhs: Expression,
+    pub rhs: Expression,
+}
+
+impl Equation {
+    /// The value of the human that makes both sides equal.
+    pub fn solve(&self) -> Option<i64> {
+        match (&self.lhs, &self.rhs) {
+            (side, Expression::Number(result)) | (Expression::Number(result), side) => {
+                side.solve(*result)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Equation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.lhs, self.rhs)
+    }
+}
+
+/// The jobs of all monkeys, which refer to each other by index. Every monkey a job refers to
+/// exists, no monkey depends on what it yells itself, and no job overflows or divides by zero.
+pub struct Monkeys {
+    jobs: Vec<Job<usize>>,
+    /// What each monkey yells, by index.
+    numbers: Vec<i64>,
+    indices: HashMap<String, usize>,
+}
+
+impl Monkeys {
+    /// What `monkey` yells, keeping the human as an unknown if `human` is set.
+    fn expression(&self, monkey: usize, human: Option<usize>) -> Expression {
+        if human == Some(monkey) {
+            return Expression::Human;
+        }
+
+        match self.jobs[monkey] {
+            Job::Number(number) => Expression::Number(number),
+            Job::Operation(lhs, operator, rhs) => {
+                match (self.expression(lhs, human), self.expression(rhs, human)) {
+                    // without the human below, this is the number worked out when parsing
+                    (Expression::Number(_), Expression::Number(_)) => {
+                        Expression::Number(self.numbers[monkey])
+                    }
+                    (lhs, rhs) => Expression::Operation(Box::new(lhs), operator, Box::new(rhs)),
+                }
+            }
+        }
+    }
+
+    /// The number `root` yells.
+    pub fn root(&self) -> i64 {
+        self.numbers[self.indices[ROOT]]
+    }
+
+    /// The equation `root` checks in part two, or `None` if `root` yells a plain number.
+    pub fn equation(&self) -> Option<Equation> {
+        let human = self.indices.get(HUMAN).copied();
+
+        match self.jobs[self.indices[ROOT]] {
+            Job::Number(_) => None,
+            Job::Operation(lhs, _, rhs) => Some(Equation {
+                lhs: self.expression(lhs, human),
+                rhs: self.expression(rhs, human),
+            }),
+        }
+    }
+}
+
+/// A monkey whose job depends on what it yells itself, if there is one.
+fn find_cycle(jobs: &[Job<usize>]) -> Option<usize> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Visit {
+        New,
+        Active,
+        Done,
+    }
+
+    fn visit(jobs: &[Job<usize>], visits: &mut [Visit], monkey: usize) -> Option<usize> {
+        match visits[monkey] {
+            Visit::Active => return Some(monkey),
+            Visit::Done => return None,
+            Visit::New => {}
+        }
+
+        visits[monkey] = Visit::Active;
+        if let Job::Operation(lhs, _, rhs) = jobs[monkey] {
+            for operand in [lhs, rhs] {
+                if let Some(cycle) = visit(jobs, visits, operand) {
+                    return Some(cycle);
+                }
+            }
+        }
+        visits[monkey] = Visit::Done;
+
+        None
+    }
+
+    let mut visits = vec![Visit::New; jobs.len()];
+    (0..jobs.len()).find_map(|monkey| visit(jobs, &mut visits, monkey))
+}
+
+/// What each monkey yells, or the first monkey whose operation overflows or divides by zero.
+/// No monkey may depend on itself.
+fn numbers(jobs: &[Job<usize>]) -> Result<Vec<i64>, usize> {
+    fn number(
+        jobs: &[Job<usize>],
+        numbers: &mut [Option<i64>],
+        monkey: usize,
+    ) -> Result<i64, usize> {
+        if let Some(number) = numbers[monkey] {
+            return Ok(number);
+        }
+
+        let number = match jobs[monkey] {
+            Job::Number(number) => number,
+            Job::Operation(lhs, operator, rhs) => {
+                let lhs = number(jobs, numbers, lhs)?;
+                let rhs = number(jobs, numbers, rhs)?;
+                operator.apply(lhs, rhs).ok_or(monkey)?
+            }
+        };
+        numbers[monkey] = Some(number);
+
+        Ok(number)
+    }
+
+    let mut numbers = vec![None; jobs.len()];
+    (0..jobs.len())
+        .map(|monkey| number(jobs, &mut numbers, monkey))
+        .collect()
+}
+
+#[aoc_generator(day21)]
+pub(crate) fn parse_input(input: &str) -> Result<Monkeys, AocError> {
+    let input = &normalize(input);
+
+    let monkeys = parse::run(21, lines(monkey), input)?;
+    let mut indices = HashMap::new();
+    for (index, (name, _)) in monkeys.iter().enumerate() {
+        if indices.insert(name.to_string(), index).is_some() {
+            return Err(AocError::at(21, input, name, "duplicate monkey"));
+        }
+    }
+
+    let index_of = |name: &str| {
+        indices
+            .get(name)
+            .copied()
+            .ok_or_else(|| AocError::at(21, input, name, "unknown monkey"))
+    };
+    let jobs = monkeys
+        .iter()
+        .map(|(_, job)| match *job {
+            Job::Number(number) => Ok(Job::Number(number)),
+            Job::Operation(lhs, operator, rhs) => {
+                Ok(Job::Operation(index_of(lhs)?, operator, index_of(rhs)?))
+            }
+        })
+        .collect::<Result<Vec<_>, AocError>>()?;
+
+    if !indices.contains_key(ROOT) {
+        let end_of_input = &input[input.trim_end().len()..];
+        return Err(AocError::at(21, input, end_of_input, "missing `root`"));
+    }
+    if let Some(monkey) = find_cycle(&jobs) {
+        let (name, _) = monkeys[monkey];
+        return Err(AocError::at(21, input, name, "monkey depends on itself"));
+    }
+    let numbers = numbers(&jobs).map_err(|monkey| {
+        let (name, _) = monkeys[monkey];
+        AocError::at(21, input, name, "operation overflows or divides by zero")
+    })?;
+
+    Ok(Monkeys {
+        jobs,
+        numbers,
+        indices,
+    })
+}
+
+#[aoc(day21, part1)]
+pub(crate) fn part1(monkeys: &Monkeys) -> i64 {
+    monkeys.root()
+}
+
+#[aoc(day21, part2)]
+pub(crate) fn part2(monkeys: &Monkeys) -> Option<i64> {
+    monkeys.equation().and_then(|equation| equation.solve())
+}
+
+pub struct Day21;
+
+impl Puzzle for Day21 {
+    type Input = Monkeys;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, monkeys: &Self::Input) -> Answer {
+        part1(monkeys).into()
+    }
+
+    fn part2(&self, monkeys: &Self::Input) -> Answer {
+        part2(monkeys).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r"root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 152);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), Some(301));
+    }
+
+    #[test]
+    fn print_equation() {
+        let equation = parse_input(TEST_INPUT).unwrap().equation().unwrap();
+
+        assert_eq!(equation.to_string(), "((4 + (2 * (humn - 3))) / 4) = 150");
+    }
+
+    #[test]
+    fn solve_human_on_the_right() {
+        let monkeys =
+            parse_input("root: abcd + efgh\nabcd: 60\nefgh: ijkl - humn\nijkl: 100\nhumn: 1")
+                .unwrap();
+        let equation = monkeys.equation().unwrap();
+
+        assert_eq!(equation.to_string(), "60 = (100 - humn)");
+        assert_eq!(equation.solve(), Some(40));
+        assert_eq!(monkeys.root(), 159);
+    }
+
+    #[test]
+    fn solve_human_on_both_sides() {
+        let monkeys = parse_input("root: humn + abcd\nabcd: humn * humn\nhumn: 1").unwrap();
+
+        assert_eq!(monkeys.equation().unwrap().solve(), None);
+        assert_eq!(
+            Day21
+                .solve(Part::Two, "root: humn + abcd\nabcd: humn * humn\nhumn: 1")
+                .unwrap(),
+            Answer::from("no solution")
+        );
+    }
+
+    #[test]
+    fn solve_without_integer_solution() {
+        let monkeys =
+            parse_input("root: abcd + efgh\nabcd: humn * ijkl\nefgh: 7\nhumn: 1\nijkl: 2").unwrap();
+
+        assert_eq!(monkeys.equation().unwrap().solve(), None);
+    }
+
+    #[test]
+    fn parse_input_invalid_arithmetic() {
+        assert_eq!(
+            parse_input("root: abcd / efgh\nabcd: 1\nefgh: 0")
+                .err()
+                .unwrap()
+                .to_string(),
+            "day 21, line 1, column 1: operation overflows or divides by zero, found `root`"
+        );
+        assert_eq!(
+            parse_input("root: abcd * abcd\nabcd: 9000000000")
+                .err()
+                .unwrap()
+                .to_string(),
+            "day 21, line 1, column 1: operation overflows or divides by zero, found `root`"
+        );
+    }
+
+    #[test]
+    fn parse_input_invalid_monkeys() {
+        assert_eq!(
+            parse_input(
+                "root: abcd + efgh
+abcd: 5"
+            )
+            .err()
+            .unwrap()
+            .to_string(),
+            "day 21, line 1, column 14: unknown monkey, found `efgh`"
+        );
+        assert_eq!(
+            parse_input(
+                "root: abcd + abcd
+abcd: root * efgh
+efgh: 2"
+            )
+            .err()
+            .unwrap()
+            .to_string(),
+            "day 21, line 1, column 1: monkey depends on itself, found `root`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(Day21.solve(Part::One, &input).unwrap(), Answer::Number(152));
+        assert_eq!(Day21.solve(Part::Two, &input).unwrap(), Answer::Number(301));
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), 152);
+        assert_eq!(part2(&parse_input(&input).unwrap()), Some(301));
+    }
+}
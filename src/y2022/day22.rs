@@ -0,0 +1,544 @@
+use crate::error::AocError;
+use crate::geometry::{Direction, Point, Point3};
+use crate::input::normalize;
+use crate::parse::{
+    self, alt, lines, many_to_end, map_res, satisfy, take_while1, PResult, ParseError,
+};
+use crate::puzzle::{Answer, Puzzle};
+use grid::*;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Tile {
+    #[default]
+    Void,
+    Open,
+    Wall,
+}
+
+impl Tile {
+    fn parse(input: &str) -> PResult<'_, Tile> {
+        map_res(satisfy(|_| true, "expected a tile"), |tile| match tile {
+            ' ' => Ok(Tile::Void),
+            '.' => Ok(Tile::Open),
+            '#' => Ok(Tile::Wall),
+            _ => Err("unknown tile"),
+        })(input)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    Forward(u32),
+    Left,
+    Right,
+}
+
+impl Step {
+    fn parse(input: &str) -> PResult<'_, Step> {
+        alt((
+            // the path has no separators, so `number` would take the turns along
+            map_res(
+                take_while1(|c| c.is_ascii_digit(), "expected a number of tiles"),
+                |tiles: &str| {
+                    tiles
+                        .parse()
+                        .map(Step::Forward)
+                        .map_err(|_| "too many tiles")
+                },
+            ),
+            map_res(satisfy(|_| true, "expected a turn"), |turn| match turn {
+                'L' => Ok(Step::Left),
+                'R' => Ok(Step::Right),
+                _ => Err("unknown turn"),
+            }),
+        ))(input)
+    }
+}
+
+/// Where a face of the net ends up on the cube. `right` and `down` are the directions of
+/// growing columns and rows of the net on that face, `normal` points out of the cube.
+#[derive(Clone, Copy, Debug)]
+struct Frame {
+    right: Point3,
+    down: Point3,
+    normal: Point3,
+}
+
+impl Frame {
+    /// The frame of the face next to this one on the net, folded along their common edge.
+    fn fold(self, direction: Direction) -> Frame {
+        let Frame {
+            right,
+            down,
+            normal,
+        } = self;
+
+        match direction {
+            Direction::Right => Frame {
+                right: -normal,
+                down,
+                normal: right,
+            },
+            Direction::Left => Frame {
+                right: normal,
+                down,
+                normal: -right,
+            },
+            Direction::Down => Frame {
+                right,
+                down: -normal,
+                normal: down,
+            },
+            Direction::Up => Frame {
+                right,
+                down: normal,
+                normal: -down,
+            },
+        }
+    }
+
+    fn axis(self, direction: Direction) -> Point3 {
+        match direction {
+            Direction::Right => self.right,
+            Direction::Left => -self.right,
+            Direction::Down => self.down,
+            Direction::Up => -self.down,
+        }
+    }
+}
+
+/// The board folded into a cube, with the frame of each face by its `(row, col)` on the net in
+/// units of faces.
+struct Cube {
+    size: i32,
+    faces: HashMap<(i32, i32), Frame>,
+}
+
+impl Cube {
+    /// Folds the board into a cube, or returns `None` if it isn't the net of one.
+    fn fold(tiles: &Grid<Tile>) -> Option<Cube> {
+        let area = tiles.iter().filter(|tile| **tile != Tile::Void).count();
+        let size = ((area / 6) as f64).sqrt() as i32;
+        if size == 0 || (size * size * 6) as usize != area {
+            return None;
+        }
+
+        // a face is a block of the board without any void, and as there are six of them in the
+        // area of six faces, every tile of the board is on one
+        let is_face = |(row, col): (i32, i32)| {
+            (0..size).all(|y| {
+                (0..size).all(|x| {
+                    Point::new(col * size + x, row * size + y)
+                        .get(tiles)
+                        .is_some_and(|tile| *tile != Tile::Void)
+                })
+            })
+        };
+        let first = (0..tiles.cols() as i32 / size)
+            .map(|col| (0, col))
+            .find(|face| is_face(*face))?;
+
+        let mut faces = HashMap::from([(
+            first,
+            Frame {
+                right: Point3::new(1, 0, 0),
+                down: Point3::new(0, 1, 0),
+                normal: Point3::new(0, 0, -1),
+            },
+        )]);
+        let mut queue = VecDeque::from(vec![first]);
+        while let Some(face) = queue.pop_front() {
+            let frame = faces[&face];
+            for direction in Direction::ALL {
+                let offset = direction.offset();
+                let neighbor = (face.0 + offset.y, face.1 + offset.x);
+                if is_face(neighbor) && !faces.contains_key(&neighbor) {
+                    faces.insert(neighbor, frame.fold(direction));
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        let mut normals: Vec<Point3> = faces.values().map(|frame| frame.normal).collect();
+        normals.sort();
+        normals.dedup();
+
+        (faces.len() == 6 && normals.len() == 6).then_some(Cube { size, faces })
+    }
+
+    /// Where walking off the edge of a face at `position` towards `facing` leads on the cube.
+    ///
+    /// Tile centers are placed on a cube spanning `-size..=size` on each axis, so that they have
+    /// odd coordinates within their face. Crossing the edge moves a tile center half a tile
+    /// towards `facing` onto the edge and half a tile along the next face.
+    fn wrap(&self, position: Point, facing: Direction) -> (Point, Direction) {
+        let size = self.size;
+        let from = self.faces[&(position.y / size, position.x / size)];
+        let center = from.normal * size
+            + from.right * (2 * (position.x % size) - size + 1)
+            + from.down * (2 * (position.y % size) - size + 1);
+
+        let heading = from.axis(facing);
+        let (&(row, col), to) = self
+            .faces
+            .iter()
+            .find(|(_, frame)| frame.normal == heading)
+            .unwrap();
+        let center = center + heading - from.normal;
+
+        let facing = Direction::ALL
+            .into_iter()
+            .find(|&direction| to.axis(direction) == -from.normal)
+            .unwrap();
+        let position = Point::new(
+            col * size + (center.dot(to.right) + size - 1) / 2,
+            row * size + (center.dot(to.down) + size - 1) / 2,
+        );
+
+        (position, facing)
+    }
+}
+
+pub struct Notes {
+    tiles: Grid<Tile>,
+    path: Vec<Step>,
+    /// The board folded into a cube, or `None` if it isn't the net of one.
+    cube: Option<Cube>,
+}
+
+/// The tiles the walk passed with the direction it last faced on each, and where it ended.
+pub struct Trail<'a> {
+    tiles: &'a Grid<Tile>,
+    facings: HashMap<Point, Direction>,
+    position: Point,
+    facing: Direction,
+}
+
+impl Trail<'_> {
+    pub fn password(&self) -> i32 {
+        let facing = match self.facing {
+            Direction::Right => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Up => 3,
+        };
+
+        1000 * (self.position.y + 1) + 4 * (self.position.x + 1) + facing
+    }
+}
+
+/// Draws the board like the puzzle's diagrams, with an arrow for the last facing on each tile
+/// the walk passed.
+impl fmt::Display for Trail<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.tiles.rows() {
+            let line: String = self
+                .tiles
+                .iter_row(row)
+                .enumerate()
+                .map(|(col, tile)| {
+                    match (tile, self.facings.get(&Point::from_grid_index(row, col))) {
+                        (Tile::Void, _) => ' ',
+                        (_, Some(Direction::Right)) => '>',
+                        (_, Some(Direction::Down)) => 'v',
+                        (_, Some(Direction::Left)) => '<',
+                        (_, Some(Direction::Up)) => '^',
+                        (Tile::Open, None) => '.',
+                        (Tile::Wall, None) => '#',
+                    }
+                })
+                .collect();
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Notes {
+    fn tile(&self, position: Point) -> Tile {
+        position.get(&self.tiles).copied().unwrap_or_default()
+    }
+
+    /// Where walking off the board at `position` towards `facing` leads on the flat board, which
+    /// is the last tile in the opposite direction.
+    fn wrap_flat(&self, position: Point, facing: Direction) -> (Point, Direction) {
+        let back = facing.opposite().offset();
+        let mut position = position;
+        while self.tile(position + back) != Tile::Void {
+            position += back;
+        }
+
+        (position, facing)
+    }
+
+    /// Follows the path, using `wrap` whenever the next tile is off the board.
+    fn walk(&self, wrap: impl Fn(Point, Direction) -> (Point, Direction)) -> Trail<'_> {
+        let start_column = self
+            .tiles
+            .iter_row(0)
+            .position(|tile| *tile == Tile::Open)
+            .expect("the top row has an open tile, checked when parsing");
+        let mut position = Point::new(start_column as i32, 0);
+        let mut facing = Direction::Right;
+        let mut facings = HashMap::from([(position, facing)]);
+
+        for step in &self.path {
+            match step {
+                Step::Left => facing = facing.turn_left(),
+                Step::Right => facing = facing.turn_right(),
+                Step::Forward(tiles) => {
+                    for _ in 0..*tiles {
+                        let (next, next_facing) = match position + facing.offset() {
+                            next if self.tile(next) == Tile::Void => wrap(position, facing),
+                            next => (next, facing),
+                        };
+                        if self.tile(next) == Tile::Wall {
+                            break;
+                        }
+
+                        (position, facing) = (next, next_facing);
+                        facings.insert(position, facing);
+                    }
+                }
+            }
+            facings.insert(position, facing);
+        }
+
+        Trail {
+            tiles: &self.tiles,
+            facings,
+            position,
+            facing,
+        }
+    }
+
+    /// The walk on the flat board of part one.
+    pub fn walk_flat(&self) -> Trail<'_> {
+        self.walk(|position, facing| self.wrap_flat(position, facing))
+    }
+
+    /// The walk on the board folded into a cube of part two, or `None` if the board doesn't
+    /// fold into one.
+    pub fn walk_cube(&self) -> Option<Trail<'_>> {
+        let cube = self.cube.as_ref()?;
+
+        Some(self.walk(|position, facing| cube.wrap(position, facing)))
+    }
+}
+
+/// The board and the path, separated by a blank line.
+fn board_and_path(input: &str) -> PResult<'_, (Vec<Vec<Tile>>, Vec<Step>)> {
+    let (board, path) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::new(
+            &input[input.len()..],
+            "expected a blank line before the path",
+        )
+    })?;
+    let (_, rows) = lines(many_to_end(Tile::parse))(board)?;
+    let (rest, path) = many_to_end(Step::parse)(path)?;
+
+    Ok((rest, (rows, path)))
+}
+
+#[aoc_generator(day22)]
+pub(crate) fn parse_input(input: &str) -> Result<Notes, AocError> {
+    let input = &normalize(input);
+
+    let (rows, path) = parse::run(22, board_and_path, input)?;
+    let cols = rows.iter().map(Vec::len).max().unwrap_or_default();
+
+    let mut tiles = Grid::new(0, cols);
+    for mut row in rows {
+        row.resize(cols, Tile::Void);
+        tiles.push_row(row);
+    }
+
+    // the path starts on the leftmost open tile of the top row
+    if tiles.rows() == 0 || !tiles.iter_row(0).any(|tile| *tile == Tile::Open) {
+        let top_row = input.lines().next().unwrap_or_default();
+        return Err(AocError::at(
+            22,
+            input,
+            top_row,
+            "no open tile in the top row",
+        ));
+    }
+
+    let cube = Cube::fold(&tiles);
+
+    Ok(Notes { tiles, path, cube })
+}
+
+#[aoc(day22, part1)]
+pub(crate) fn part1(notes: &Notes) -> i32 {
+    notes.walk_flat().password()
+}
+
+#[aoc(day22, part2)]
+pub(crate) fn part2(notes: &Notes) -> Option<i32> {
+    notes.walk_cube().map(|trail| trail.password())
+}
+
+pub struct Day22;
+
+impl Puzzle for Day22 {
+    type Input = Notes;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, notes: &Self::Input) -> Answer {
+        part1(notes).into()
+    }
+
+    fn part2(&self, notes: &Self::Input) -> Answer {
+        part2(notes).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r"        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5";
+
+    /// Nets of a cube with two tiles per edge, all open.
+    static NETS: [&str; 3] = [
+        "  ..\n  ..\n......\n......\n  ..\n  ..\n  ..\n  ..\n\n1",
+        "..\n..\n....\n....\n  ....\n  ....\n    ..\n    ..\n\n1",
+        "......\n......\n    ......\n    ......\n\n1",
+    ];
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 6032);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), Some(5031));
+    }
+
+    #[test]
+    fn render_trail() {
+        let notes = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(
+            notes.walk_flat().to_string(),
+            r"        >>v#
+        .#v.
+        #.v.
+        ..v.
+...#...v..v#
+>>>v...>#.>>
+..#v...#....
+...>>>>v..#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+"
+        );
+    }
+
+    #[test]
+    fn fold_any_net() {
+        for net in NETS.iter().chain([&TEST_INPUT]) {
+            let notes = parse_input(net).unwrap();
+            let cube = notes.cube.as_ref().unwrap();
+            let size = cube.size;
+
+            for (row, col) in (0..notes.tiles.rows())
+                .flat_map(|row| (0..notes.tiles.cols()).map(move |col| (row, col)))
+            {
+                let start = Point::from_grid_index(row, col);
+                if notes.tile(start) == Tile::Void {
+                    continue;
+                }
+
+                for facing in Direction::ALL {
+                    // going straight around the cube ends where it started
+                    let mut state = (start, facing);
+                    for _ in 0..4 * size {
+                        let (position, facing) = state;
+                        state = match position + facing.offset() {
+                            next if notes.tile(next) == Tile::Void => cube.wrap(position, facing),
+                            next => (next, facing),
+                        };
+                    }
+                    assert_eq!(state, (start, facing), "{net}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unfilled_faces() {
+        let board = "......\n. ....\n   .......\n    ......\n\n";
+
+        for path in ["R3L5", "3R3L3R3"] {
+            let input = format!("{board}{path}");
+            let notes = parse_input(&input).unwrap();
+
+            assert!(notes.cube.is_none());
+            assert_eq!(
+                Day22.solve(Part::Two, &input).unwrap(),
+                Answer::Text("no solution".to_owned())
+            );
+        }
+    }
+
+    #[test]
+    fn flat_board_only() {
+        let input = "...\n...\n\n2R1";
+
+        assert_eq!(Day22.solve(Part::One, input).unwrap(), Answer::Number(2013));
+        assert_eq!(
+            Day22.solve(Part::Two, input).unwrap(),
+            Answer::Text("no solution".to_owned())
+        );
+    }
+
+    #[test]
+    fn parse_input_no_start() {
+        assert_eq!(
+            parse_input("  ##\n  ..\n\n1").err().unwrap().to_string(),
+            "day 22, line 1, column 1: no open tile in the top row, found `  ##`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(
+            Day22.solve(Part::One, &input).unwrap(),
+            Answer::Number(6032)
+        );
+        assert_eq!(
+            Day22.solve(Part::Two, &input).unwrap(),
+            Answer::Number(5031)
+        );
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), 6032);
+        assert_eq!(part2(&parse_input(&input).unwrap()), Some(5031));
+    }
+}
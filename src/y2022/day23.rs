@@ -0,0 +1,234 @@
+use crate::error::AocError;
+use crate::geometry::{Direction, Point};
+use crate::input::normalize;
+use crate::parse::{self, lines, many_to_end, map_res, satisfy};
+use crate::puzzle::{Answer, Puzzle};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// The directions the elves consider moving in, in the order of the first round.
+const PROPOSALS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+/// The elves on the ground, with north being up.
+#[derive(Clone)]
+pub struct Grove {
+    elves: HashSet<Point>,
+    rounds: usize,
+}
+
+impl Grove {
+    /// Whether no elf is in the three positions towards `direction` from `elf`.
+    fn is_clear(&self, elf: Point, direction: Direction) -> bool {
+        let ahead = elf + direction.offset();
+        let side = direction.turn_left().offset();
+
+        [ahead - side, ahead, ahead + side]
+            .iter()
+            .all(|position| !self.elves.contains(position))
+    }
+
+    /// Plays one round and returns whether any elf moved.
+    pub fn round(&mut self) -> bool {
+        // the position each elf proposes to move to, or `None` if several elves propose it
+        let mut proposals: HashMap<Point, Option<Point>> = HashMap::new();
+
+        for &elf in &self.elves {
+            if elf
+                .neighbors8()
+                .all(|neighbor| !self.elves.contains(&neighbor))
+            {
+                continue;
+            }
+
+            let direction = (0..PROPOSALS.len())
+                .map(|turn| PROPOSALS[(self.rounds + turn) % PROPOSALS.len()])
+                .find(|&direction| self.is_clear(elf, direction));
+            if let Some(direction) = direction {
+                proposals
+                    .entry(elf + direction.offset())
+                    .and_modify(|proposer| *proposer = None)
+                    .or_insert(Some(elf));
+            }
+        }
+
+        let mut moved = false;
+        for (target, proposer) in proposals {
+            if let Some(elf) = proposer {
+                self.elves.remove(&elf);
+                self.elves.insert(target);
+                moved = true;
+            }
+        }
+        self.rounds += 1;
+
+        moved
+    }
+
+    /// The grove after `rounds` more rounds.
+    pub fn after(&self, rounds: usize) -> Grove {
+        let mut grove = self.clone();
+        for _ in 0..rounds {
+            grove.round();
+        }
+        grove
+    }
+
+    /// The top left and bottom right corner of the smallest rectangle containing every elf.
+    fn bounds(&self) -> (Point, Point) {
+        let xs = self.elves.iter().map(|elf| elf.x);
+        let ys = self.elves.iter().map(|elf| elf.y);
+
+        (
+            Point::new(xs.clone().min().unwrap_or(0), ys.clone().min().unwrap_or(0)),
+            Point::new(xs.max().unwrap_or(-1), ys.max().unwrap_or(-1)),
+        )
+    }
+
+    fn empty_ground(&self) -> usize {
+        let (min, max) = self.bounds();
+        let area = (max.x - min.x + 1) * (max.y - min.y + 1);
+
+        area as usize - self.elves.len()
+    }
+}
+
+/// Draws the smallest rectangle containing every elf like the puzzle's diagrams.
+impl fmt::Display for Grove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (min, max) = self.bounds();
+
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let ground = if self.elves.contains(&Point::new(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                };
+                write!(f, "{ground}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[aoc_generator(day23)]
+pub(crate) fn parse_input(input: &str) -> Result<Grove, AocError> {
+    let input = &normalize(input);
+
+    let ground = map_res(
+        satisfy(|_| true, "expected ground"),
+        |ground| match ground {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("unknown ground"),
+        },
+    );
+    let rows = parse::run(23, lines(many_to_end(ground)), input)?;
+
+    let elves = rows
+        .iter()
+        .enumerate()
+        .flat_map(|(row, ground)| {
+            ground
+                .iter()
+                .enumerate()
+                .filter(|(_, elf)| **elf)
+                .map(move |(col, _)| Point::from_grid_index(row, col))
+        })
+        .collect();
+
+    Ok(Grove { elves, rounds: 0 })
+}
+
+#[aoc(day23, part1)]
+pub(crate) fn part1(grove: &Grove) -> usize {
+    grove.after(10).empty_ground()
+}
+
+#[aoc(day23, part2)]
+pub(crate) fn part2(grove: &Grove) -> usize {
+    let mut grove = grove.clone();
+    while grove.round() {}
+
+    grove.rounds
+}
+
+pub struct Day23;
+
+impl Puzzle for Day23 {
+    type Input = Grove;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, grove: &Self::Input) -> Answer {
+        part1(grove).into()
+    }
+
+    fn part2(&self, grove: &Self::Input) -> Answer {
+        part2(grove).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r"....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 110);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), 20);
+    }
+
+    #[test]
+    fn dump_board() {
+        let grove = parse_input(".....\n..##.\n..#..\n.....\n..##.\n.....").unwrap();
+
+        assert_eq!(grove.to_string(), "##\n#.\n..\n##\n");
+        assert_eq!(grove.after(1).to_string(), "##\n..\n#.\n.#\n#.\n");
+        assert_eq!(
+            grove.after(3).to_string(),
+            "..#..\n....#\n#....\n....#\n.....\n..#..\n"
+        );
+    }
+
+    #[test]
+    fn parse_input_unknown_ground() {
+        assert_eq!(
+            parse_input("..#\n.x.").err().unwrap().to_string(),
+            "day 23, line 2, column 2: unknown ground, found `x`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(Day23.solve(Part::One, &input).unwrap(), Answer::Number(110));
+        assert_eq!(Day23.solve(Part::Two, &input).unwrap(), Answer::Number(20));
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), 110);
+        assert_eq!(part2(&parse_input(&input).unwrap()), 20);
+    }
+}
@@ -0,0 +1,285 @@
+use crate::error::AocError;
+use crate::geometry::{Direction, Point};
+use crate::input::normalize;
+use crate::parse::{self, lines, many_to_end, map_res, satisfy, PResult};
+use crate::puzzle::{Answer, Puzzle};
+use crate::search::bfs;
+use grid::*;
+use std::iter;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Tile {
+    #[default]
+    Wall,
+    Ground,
+    Blizzard(Direction),
+}
+
+impl Tile {
+    fn parse(input: &str) -> PResult<'_, Tile> {
+        map_res(satisfy(|_| true, "expected a tile"), |tile| match tile {
+            '#' => Ok(Tile::Wall),
+            '.' => Ok(Tile::Ground),
+            '^' => Ok(Tile::Blizzard(Direction::Up)),
+            'v' => Ok(Tile::Blizzard(Direction::Down)),
+            '<' => Ok(Tile::Blizzard(Direction::Left)),
+            '>' => Ok(Tile::Blizzard(Direction::Right)),
+            _ => Err("unknown tile"),
+        })(input)
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+/// The valley with the blizzards worked out for every minute. Blizzards wrap around inside the
+/// walls, so their positions repeat after the least common multiple of its width and height.
+pub struct Basin {
+    /// The positions blocked by a wall or a blizzard, for each minute of a period.
+    blocked: Vec<Grid<bool>>,
+    entrance: Point,
+    exit: Point,
+}
+
+impl Basin {
+    fn new(tiles: &Grid<Tile>, entrance: Point, exit: Point) -> Basin {
+        let (rows, cols) = (tiles.rows(), tiles.cols());
+        let (width, height) = (cols as i32 - 2, rows as i32 - 2);
+        let period = lcm(width as usize, height as usize);
+
+        let mut walls = Grid::new(rows, cols);
+        let mut blizzards = Vec::new();
+        for row in 0..rows {
+            for col in 0..cols {
+                match tiles.get(row, col) {
+                    Some(Tile::Wall) => *walls.get_mut(row, col).unwrap() = true,
+                    Some(&Tile::Blizzard(direction)) => {
+                        blizzards.push((Point::from_grid_index(row, col), direction))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let blocked = (0..period as i32)
+            .map(|minute| {
+                let mut blocked = walls.clone();
+                for &(start, direction) in &blizzards {
+                    let moved = start - Point::new(1, 1) + direction.offset() * minute;
+                    let position = Point::new(
+                        moved.x.rem_euclid(width) + 1,
+                        moved.y.rem_euclid(height) + 1,
+                    );
+                    let (row, col) = position.to_grid_index(&blocked).unwrap();
+                    *blocked.get_mut(row, col).unwrap() = true;
+                }
+                blocked
+            })
+            .collect();
+
+        Basin {
+            blocked,
+            entrance,
+            exit,
+        }
+    }
+
+    fn period(&self) -> usize {
+        self.blocked.len()
+    }
+
+    fn is_open(&self, position: Point, minute: usize) -> bool {
+        position.get(&self.blocked[minute % self.period()]) == Some(&false)
+    }
+
+    /// The earliest minute to reach `to` when leaving `from` at minute `start`, or `None` if the
+    /// blizzards never let through. The minute is part of each state of the search, and as
+    /// positions repeat, minutes only count modulo the period.
+    pub fn crossing(&self, from: Point, to: Point, start: usize) -> Option<usize> {
+        bfs(
+            (from, start),
+            |&(position, minute)| {
+                // waiting is a move too
+                iter::once(position)
+                    .chain(position.neighbors4())
+                    .filter(move |&next| self.is_open(next, minute + 1))
+                    .map(move |next| (next, minute + 1))
+            },
+            |&(position, minute)| (position, minute % self.period()),
+            |&(position, _)| position == to,
+        )
+        .map(|((_, minute), _)| minute)
+    }
+}
+
+#[aoc_generator(day24)]
+pub(crate) fn parse_input(input: &str) -> Result<Basin, AocError> {
+    let input = &normalize(input);
+
+    let rows = parse::run(24, lines(many_to_end(Tile::parse)), input)?;
+    let lines: Vec<&str> = input.lines().collect();
+    let mut tiles = Grid::new(0, 0);
+
+    for (y, (row, line)) in rows.into_iter().zip(&lines).enumerate() {
+        if row.is_empty() {
+            return Err(AocError::at(24, input, line, "empty row"));
+        }
+        if y > 0 && row.len() != tiles.cols() {
+            return Err(AocError::at(24, input, line, "rows must have equal width"));
+        }
+        tiles.push_row(row);
+    }
+
+    if tiles.rows() < 3 || tiles.cols() < 3 {
+        let end_of_input = &input[input.trim_end().len()..];
+        return Err(AocError::at(24, input, end_of_input, "basin is too small"));
+    }
+
+    // the border is all wall, but for the entrance in the top row and the exit in the bottom row
+    let last_row = tiles.rows() - 1;
+    let last_col = tiles.cols() - 1;
+    let mut openings = [None, None];
+    for row in 0..=last_row {
+        for col in 0..=last_col {
+            let tile = tiles.get(row, col).unwrap();
+            let on_border = row == 0 || row == last_row || col == 0 || col == last_col;
+            let opening = (row == 0 || row == last_row) && col > 0 && col < last_col;
+            let token = &lines[row][col..col + 1];
+
+            match tile {
+                Tile::Blizzard(_) if on_border => {
+                    return Err(AocError::at(24, input, token, "blizzard outside the basin"));
+                }
+                Tile::Ground if on_border && !opening => {
+                    return Err(AocError::at(24, input, token, "gap in the wall"));
+                }
+                Tile::Ground if opening => {
+                    let side = &mut openings[usize::from(row == last_row)];
+                    if side.is_some() {
+                        return Err(AocError::at(24, input, token, "gap in the wall"));
+                    }
+                    *side = Some(Point::from_grid_index(row, col));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let [Some(entrance), Some(exit)] = openings else {
+        let line = if openings[0].is_none() {
+            lines[0]
+        } else {
+            lines[last_row]
+        };
+        return Err(AocError::at(24, input, line, "missing gap in the wall"));
+    };
+
+    Ok(Basin::new(&tiles, entrance, exit))
+}
+
+#[aoc(day24, part1)]
+pub(crate) fn part1(basin: &Basin) -> Option<usize> {
+    basin.crossing(basin.entrance, basin.exit, 0)
+}
+
+#[aoc(day24, part2)]
+pub(crate) fn part2(basin: &Basin) -> Option<usize> {
+    basin
+        .crossing(basin.entrance, basin.exit, 0)
+        .and_then(|there| basin.crossing(basin.exit, basin.entrance, there))
+        .and_then(|back| basin.crossing(basin.entrance, basin.exit, back))
+}
+
+pub struct Day24;
+
+impl Puzzle for Day24 {
+    type Input = Basin;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, basin: &Self::Input) -> Answer {
+        part1(basin).into()
+    }
+
+    fn part2(&self, basin: &Self::Input) -> Answer {
+        part2(basin).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+
+    static TEST_INPUT: &str = r"#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), Some(18));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), Some(54));
+    }
+
+    #[test]
+    fn blizzards_repeat() {
+        let basin = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(basin.period(), 12);
+        assert_eq!(basin.crossing(basin.exit, basin.entrance, 18), Some(41));
+    }
+
+    #[test]
+    fn parse_input_invalid_walls() {
+        assert_eq!(
+            parse_input("#.###\n#...#\n>...#\n###.#")
+                .err()
+                .unwrap()
+                .to_string(),
+            "day 24, line 3, column 1: blizzard outside the basin, found `>`"
+        );
+        assert_eq!(
+            parse_input("#.###\n#...#\n#####")
+                .err()
+                .unwrap()
+                .to_string(),
+            "day 24, line 3, column 1: missing gap in the wall, found `#####`"
+        );
+        assert_eq!(
+            parse_input("\n#.###\n#...#\n###.#")
+                .err()
+                .unwrap()
+                .to_string(),
+            "day 24, line 1, column 1: empty row, found end of line"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(Day24.solve(Part::One, &input).unwrap(), Answer::Number(18));
+        assert_eq!(Day24.solve(Part::Two, &input).unwrap(), Answer::Number(54));
+
+        assert_eq!(part1(&parse_input(&input).unwrap()), Some(18));
+        assert_eq!(part2(&parse_input(&input).unwrap()), Some(54));
+    }
+}
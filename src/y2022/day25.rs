@@ -0,0 +1,316 @@
+use crate::error::AocError;
+use crate::input::normalize;
+use crate::parse::{self, lines, map_res, take_while1};
+use crate::puzzle::{Answer, Puzzle};
+use std::error::Error;
+use std::fmt;
+use std::iter::Sum;
+use std::num::TryFromIntError;
+use std::ops::Add;
+use std::str::FromStr;
+
+/// A number in SNAFU, the balanced base five of the hot air balloons, with the digits `=`, `-`,
+/// `0`, `1` and `2` for -2 up to 2.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Snafu(i128);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseSnafuError {
+    Empty,
+    InvalidDigit,
+    Overflow,
+}
+
+impl ParseSnafuError {
+    fn reason(self) -> &'static str {
+        match self {
+            ParseSnafuError::Empty => "empty SNAFU number",
+            ParseSnafuError::InvalidDigit => "invalid SNAFU digit",
+            ParseSnafuError::Overflow => "SNAFU number too large",
+        }
+    }
+}
+
+impl fmt::Display for ParseSnafuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.reason())
+    }
+}
+
+impl Error for ParseSnafuError {}
+
+impl FromStr for Snafu {
+    type Err = ParseSnafuError;
+
+    fn from_str(digits: &str) -> Result<Snafu, ParseSnafuError> {
+        if digits.is_empty() {
+            return Err(ParseSnafuError::Empty);
+        }
+
+        digits.chars().try_fold(Snafu(0), |Snafu(value), digit| {
+            let digit = match digit {
+                '2' => 2,
+                '1' => 1,
+                '0' => 0,
+                '-' => -1,
+                '=' => -2,
+                _ => return Err(ParseSnafuError::InvalidDigit),
+            };
+
+            // `4 * value + (value + digit)` rather than `5 * value + digit`, which overflows on
+            // the way to `i128::MIN` as its last digit is positive
+            value
+                .checked_mul(4)
+                .zip(value.checked_add(digit))
+                .and_then(|(value, rest)| value.checked_add(rest))
+                .map(Snafu)
+                .ok_or(ParseSnafuError::Overflow)
+        })
+    }
+}
+
+impl fmt::Display for Snafu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 == 0 {
+            return write!(f, "0");
+        }
+
+        let mut digits = Vec::new();
+        let mut value = self.0;
+        while value != 0 {
+            // a remainder of 3 or 4 is a digit of -2 or -1 and a carry into the next place
+            let (digit, carry) = match value.rem_euclid(5) {
+                0 => ('0', 0),
+                1 => ('1', 0),
+                2 => ('2', 0),
+                3 => ('=', 1),
+                _ => ('-', 1),
+            };
+            digits.push(digit);
+            value = value.div_euclid(5) + carry;
+        }
+
+        write!(f, "{}", digits.iter().rev().collect::<String>())
+    }
+}
+
+impl Snafu {
+    /// The sum of two numbers, or `None` if it is too large, as [`FromStr`] rejects it.
+    pub fn checked_add(self, other: Snafu) -> Option<Snafu> {
+        self.0.checked_add(other.0).map(Snafu)
+    }
+}
+
+/// Panics if the sum is too large, use [`Snafu::checked_add`] to check for that instead.
+impl Add for Snafu {
+    type Output = Snafu;
+
+    fn add(self, other: Snafu) -> Snafu {
+        self.checked_add(other).expect("SNAFU sum too large")
+    }
+}
+
+/// Panics if the sum is too large, like [`Add`].
+impl Sum for Snafu {
+    fn sum<I: Iterator<Item = Snafu>>(iter: I) -> Snafu {
+        iter.fold(Snafu::default(), Add::add)
+    }
+}
+
+impl From<i64> for Snafu {
+    fn from(value: i64) -> Snafu {
+        Snafu(value.into())
+    }
+}
+
+impl From<i128> for Snafu {
+    fn from(value: i128) -> Snafu {
+        Snafu(value)
+    }
+}
+
+impl From<Snafu> for i128 {
+    fn from(snafu: Snafu) -> i128 {
+        snafu.0
+    }
+}
+
+impl TryFrom<Snafu> for i64 {
+    type Error = TryFromIntError;
+
+    fn try_from(snafu: Snafu) -> Result<i64, TryFromIntError> {
+        snafu.0.try_into()
+    }
+}
+
+#[aoc_generator(day25)]
+pub(crate) fn parse_input(input: &str) -> Result<Vec<Snafu>, AocError> {
+    let input = &normalize(input);
+
+    let snafu = map_res(
+        take_while1(|c| !c.is_whitespace(), "expected a SNAFU number"),
+        |digits: &str| digits.parse().map_err(ParseSnafuError::reason),
+    );
+
+    parse::run(25, lines(snafu), input)
+}
+
+/// The total fuel requirement in SNAFU, or `None` if it is too large.
+#[aoc(day25, part1)]
+pub(crate) fn part1(fuel_requirements: &[Snafu]) -> Option<String> {
+    fuel_requirements
+        .iter()
+        .try_fold(Snafu::default(), |total, &fuel| total.checked_add(fuel))
+        .map(|total| total.to_string())
+}
+
+pub struct Day25;
+
+impl Puzzle for Day25 {
+    type Input = Vec<Snafu>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, fuel_requirements: &Self::Input) -> Answer {
+        part1(fuel_requirements).into()
+    }
+
+    /// There is no second puzzle on the last day, its star is for having all the others.
+    fn part2(&self, _: &Self::Input) -> Answer {
+        "Merry Christmas!".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{Part, Solver};
+    use proptest::prelude::*;
+
+    static TEST_INPUT: &str = r"1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            part1(&parse_input(TEST_INPUT).unwrap()).as_deref(),
+            Some("2=-1=0")
+        );
+    }
+
+    #[test]
+    fn snafu_examples() {
+        let examples = [
+            (1, "1"),
+            (3, "1="),
+            (8, "2="),
+            (10, "20"),
+            (15, "1=0"),
+            (2022, "1=11-2"),
+            (12345, "1-0---0"),
+            (314159265, "1121-1110-1=0"),
+        ];
+
+        for (decimal, snafu) in examples {
+            assert_eq!(Snafu::from(decimal as i64).to_string(), snafu);
+            assert_eq!(snafu.parse(), Ok(Snafu::from(decimal as i64)));
+        }
+        assert_eq!(Snafu::default().to_string(), "0");
+        assert_eq!(Snafu::from(-3_i64).to_string(), "-2");
+    }
+
+    #[test]
+    fn snafu_limits() {
+        for value in [i128::MIN, i128::MAX] {
+            assert_eq!(
+                Snafu::from(value).to_string().parse(),
+                Ok(Snafu::from(value))
+            );
+        }
+        assert_eq!(
+            format!("1{}", Snafu::from(i128::MAX)).parse::<Snafu>(),
+            Err(ParseSnafuError::Overflow)
+        );
+        assert!(i64::try_from(Snafu::from(i128::from(i64::MAX) + 1)).is_err());
+    }
+
+    #[test]
+    fn sum_too_large() {
+        let largest = "2".repeat(55);
+        let input = format!("{largest}\n{largest}");
+        let requirements = parse_input(&input).unwrap();
+
+        assert_eq!(requirements[0].checked_add(requirements[1]), None);
+        assert_eq!(part1(&requirements), None);
+        assert_eq!(
+            Day25.solve(Part::One, &input).unwrap(),
+            Answer::Text("no solution".to_owned())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "SNAFU sum too large")]
+    fn add_too_large() {
+        let _ = Snafu::from(i128::MAX) + Snafu::from(1_i64);
+    }
+
+    #[test]
+    fn parse_input_invalid_digit() {
+        assert_eq!(
+            parse_input("1=-0-2\n12311").err().unwrap().to_string(),
+            "day 25, line 2, column 1: invalid SNAFU digit, found `12311`"
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = TEST_INPUT.replace('\n', "\r\n") + "\r\n";
+
+        assert_eq!(
+            Day25.solve(Part::One, &input).unwrap(),
+            Answer::Text("2=-1=0".to_owned())
+        );
+
+        assert_eq!(
+            part1(&parse_input(&input).unwrap()).as_deref(),
+            Some("2=-1=0")
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip_i128(value in any::<i128>()) {
+            let snafu = Snafu::from(value);
+
+            prop_assert_eq!(snafu.to_string().parse::<Snafu>(), Ok(snafu));
+            prop_assert_eq!(i128::from(snafu), value);
+        }
+
+        #[test]
+        fn round_trip_i64(value in any::<i64>()) {
+            let snafu: Snafu = Snafu::from(value).to_string().parse().unwrap();
+
+            prop_assert_eq!(i64::try_from(snafu), Ok(value));
+        }
+
+        #[test]
+        fn add_matches_integers(lhs in any::<i64>(), rhs in any::<i64>()) {
+            let sum = Snafu::from(lhs) + Snafu::from(rhs);
+
+            prop_assert_eq!(i128::from(sum), i128::from(lhs) + i128::from(rhs));
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Advent of Code 2022.
+
+use crate::runner::Registry;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub const YEAR: u32 = 2022;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(YEAR, 1, day01::Day01);
+    registry.register(YEAR, 2, day02::Day02);
+    registry.register(YEAR, 3, day03::Day03);
+    registry.register(YEAR, 4, day04::Day04);
+    registry.register(YEAR, 5, day05::Day05);
+    registry.register(YEAR, 6, day06::Day06);
+    registry.register(YEAR, 7, day07::Day07);
+    registry.register(YEAR, 8, day08::Day08);
+    registry.register(YEAR, 9, day09::Day09);
+    registry.register(YEAR, 10, day10::Day10);
+    registry.register(YEAR, 11, day11::Day11);
+    registry.register(YEAR, 12, day12::Day12);
+    registry.register(YEAR, 13, day13::Day13);
+    registry.register(YEAR, 14, day14::Day14);
+    registry.register(YEAR, 15, day15::Day15);
+    registry.register(YEAR, 16, day16::Day16);
+    registry.register(YEAR, 17, day17::Day17);
+    registry.register(YEAR, 18, day18::Day18);
+    registry.register(YEAR, 19, day19::Day19);
+    registry.register(YEAR, 20, day20::Day20);
+    registry.register(YEAR, 21, day21::Day21);
+    registry.register(YEAR, 22, day22::Day22);
+    registry.register(YEAR, 23, day23::Day23);
+    registry.register(YEAR, 24, day24::Day24);
+    registry.register(YEAR, 25, day25::Day25);
+}